@@ -1,37 +1,17 @@
+#[path = "build/glyphs.rs"]
+mod glyphs;
+
 use anyhow::anyhow;
+use glyphs::{Glyph, parse_glyphs, supported_widths};
 use std::path::Path;
 use std::{env, fs, path};
 
-/// A single glyph in a font, consisting of a codepoint and a bitmap.
-struct Glyph(u32, Vec<u8>);
-
-impl Glyph {
-    /// Parse a line from a font file into a `Glyph` struct.
-    ///
-    /// The line should be in the format `XXXX:YY...YY`, where `XXXX` is the hexadecimal codepoint,
-    /// and `YY...YY` is a sequence of hexadecimal digits representing the raw bitmap data.
-    fn from_line(line_number: usize, line: &str) -> anyhow::Result<Self> {
-        let error = || anyhow::anyhow!("Invalid line: {line_number}: {line}");
-        let (codepoint, data) = line.split_once(':').ok_or_else(error)?;
-        let codepoint = u32::from_str_radix(codepoint, 16).map_err(|_| error())?;
-        let mut bitmap: Vec<u8> = vec![0; data.len() / 2];
-        hex::decode_to_slice(data, &mut bitmap).map_err(|_| error())?;
-        if bitmap.len() != 16 && bitmap.len() != 32 {
-            return Err(error());
-        }
-        Ok(Self(codepoint, bitmap))
-    }
-
-    /// Calculate the width of the glyph in pixels, assuming a height of 16 pixels.
-    fn width(&self) -> u8 {
-        (self.1.len()) as u8 / 2
-    }
+/// Glyph height assumed when the metadata doesn’t specify one, as in Unifont.
+const DEFAULT_HEIGHT: usize = 16;
 
-    /// Convert the glyph to a binary representation.
-    fn append_to_vec(&self, out: &mut Vec<u8>) {
-        out.extend_from_slice(&self.0.to_ne_bytes());
-        out.extend_from_slice(&self.1);
-    }
+/// Whether the `wide-glyphs` feature is enabled, allowing 24 and 32 pixels wide glyphs.
+fn wide_glyphs_enabled() -> bool {
+    env::var_os("CARGO_FEATURE_WIDE_GLYPHS").is_some()
 }
 
 fn output_file_path<P: AsRef<Path>>(filename: P) -> anyhow::Result<path::PathBuf> {
@@ -40,46 +20,72 @@ fn output_file_path<P: AsRef<Path>>(filename: P) -> anyhow::Result<path::PathBuf
 }
 
 /// Load all glyphs from the `fonts` directory into a vector, sorted by codepoint.
+///
+/// Besides the version, the metadata may specify the `height` of the glyphs, for hex fonts other
+/// than Unifont (e.g. 8 or 32 pixels); it defaults to 16. Every problem found in the font file is
+/// emitted as a cargo warning before failing.
 fn load_font(
     metadata_file: &Path,
     unifont_hex_file: &Path,
 ) -> anyhow::Result<(String, Vec<Glyph>)> {
-    let version = {
-        // Load metadata to get version
-        let contents = fs::read_to_string(metadata_file)?;
-        let metadata: serde_json::Value = serde_json::from_str(&contents)?;
-        metadata["version"]
-            .as_str()
-            .ok_or_else(|| anyhow!("Missing version in metadata"))?
-            .to_string()
+    // Load metadata to get version and glyph height
+    let contents = fs::read_to_string(metadata_file)?;
+    let metadata: serde_json::Value = serde_json::from_str(&contents)?;
+    let version = metadata["version"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Missing version in metadata"))?
+        .to_string();
+    let height = match &metadata["height"] {
+        serde_json::Value::Null => DEFAULT_HEIGHT,
+        value => value
+            .as_u64()
+            .filter(|&h| h > 0 && h <= u16::MAX as u64)
+            .ok_or_else(|| anyhow!("Invalid height in metadata: {value}"))?
+            as usize,
     };
 
     // Load glyphs from unifont.hex
-    let all_glyphs = {
-        let contents = fs::read_to_string(unifont_hex_file)?;
-        let mut all_glyphs = contents
-            .lines()
-            .enumerate()
-            .map(|(line_number, line)| Glyph::from_line(line_number, line))
-            .collect::<anyhow::Result<Vec<Glyph>>>()?;
-        all_glyphs.sort_by_key(|g| g.0);
-        all_glyphs
-    };
+    let contents = fs::read_to_string(unifont_hex_file)?;
+    let all_glyphs =
+        parse_glyphs(&contents, height, wide_glyphs_enabled()).map_err(|problems| {
+            for problem in &problems {
+                println!("cargo:warning={problem}");
+            }
+            anyhow!(
+                "Found {} problem(s) in {}:\n{}",
+                problems.len(),
+                unifont_hex_file.display(),
+                problems.join("\n")
+            )
+        })?;
 
     Ok((version, all_glyphs))
 }
 
-/// Save all glyphs with a given width to a binary file.
+/// Save all glyphs to a binary file, as one table per glyph width.
+///
+/// Each table starts with an 8-byte header made of the glyph width (`u16`), the glyph height
+/// (`u16`) and the number of records (`u32`), followed by the records themselves, sorted by
+/// codepoint. Each record holds the codepoint (`u32`) and the bitmap, whose rows are padded to a
+/// whole number of bytes. All integers are in native byte order.
 fn save_unifont_glyphs_bin<P: AsRef<Path>>(
     glyphs: &[Glyph],
-    width: u8,
     filename: P,
 ) -> anyhow::Result<String> {
     let mut data = Vec::new();
     let font_file = output_file_path(filename)?;
 
-    for glyph in glyphs.iter().filter(|g| g.width() == width) {
-        glyph.append_to_vec(&mut data);
+    for &width in supported_widths(wide_glyphs_enabled()) {
+        let table: Vec<&Glyph> = glyphs.iter().filter(|g| g.width() == width).collect();
+        let Some(height) = table.first().map(|g| g.height) else {
+            continue; // No glyphs with this width
+        };
+        data.extend_from_slice(&(width as u16).to_ne_bytes());
+        data.extend_from_slice(&(height as u16).to_ne_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_ne_bytes());
+        for glyph in table {
+            glyph.append_to_vec(&mut data);
+        }
     }
 
     fs::write(&font_file, &data)?;
@@ -116,14 +122,12 @@ fn main() -> anyhow::Result<()> {
     let (version, all_glyphs) = load_font(&metadata_file, &unifont_hex_file)?;
 
     let version_file = save_unifont_version(&version)?;
-    let glyphs_8x16_file = save_unifont_glyphs_bin(&all_glyphs, 8, "unifont_glyphs_8x16.bin")?;
-    let glyphs_16x16_file = save_unifont_glyphs_bin(&all_glyphs, 16, "unifont_glyphs_16x16.bin")?;
+    let glyphs_file = save_unifont_glyphs_bin(&all_glyphs, "unifont_glyphs.bin")?;
 
     println!("cargo:rerun-if-changed={}", metadata_file.display());
     println!("cargo:rerun-if-changed={}", unifont_hex_file.display());
     println!("cargo:rustc-env=UNIFONT_VERSION_FILE={version_file}");
-    println!("cargo:rustc-env=UNIFONT_GLYPHS_8X16_FILE={glyphs_8x16_file}");
-    println!("cargo:rustc-env=UNIFONT_GLYPHS_16X16_FILE={glyphs_16x16_file}");
+    println!("cargo:rustc-env=UNIFONT_GLYPHS_FILE={glyphs_file}");
 
     Ok(())
 }
@@ -0,0 +1,254 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Parsing of hex font files, shared by the build script and the crate’s tests.
+
+/// Highest valid Unicode codepoint.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+/// A single glyph in a font, consisting of a codepoint and a bitmap.
+pub struct Glyph {
+    pub codepoint: u32,
+    pub bitmap: Vec<u8>,
+    /// Height of the glyph in pixels.
+    pub height: usize,
+    /// Line of the font file where the glyph is defined (1-based).
+    pub line_number: usize,
+}
+
+impl Glyph {
+    /// Parse a line from a font file into a `Glyph` struct.
+    ///
+    /// The line should be in the format `XXXX:YY...YY`, where `XXXX` is the hexadecimal codepoint,
+    /// and `YY...YY` is a sequence of hexadecimal digits representing the raw bitmap data, whose
+    /// length must be a multiple of the glyph height.
+    fn from_line(line_number: usize, line: &str, height: usize) -> anyhow::Result<Self> {
+        let error = || anyhow::anyhow!("Invalid line: {line_number}: {line}");
+        let (codepoint, data) = line.split_once(':').ok_or_else(error)?;
+        let codepoint = u32::from_str_radix(codepoint, 16).map_err(|_| error())?;
+        let mut bitmap: Vec<u8> = vec![0; data.len() / 2];
+        hex::decode_to_slice(data, &mut bitmap).map_err(|_| error())?;
+        if bitmap.is_empty() || !bitmap.len().is_multiple_of(height) {
+            return Err(error());
+        }
+        Ok(Self {
+            codepoint,
+            bitmap,
+            height,
+            line_number,
+        })
+    }
+
+    /// Calculate the width of the glyph in pixels, from its height and the size of its bitmap.
+    pub fn width(&self) -> usize {
+        self.bitmap.len() / self.height * 8
+    }
+
+    /// Convert the glyph to a binary representation.
+    pub fn append_to_vec(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.codepoint.to_ne_bytes());
+        out.extend_from_slice(&self.bitmap);
+    }
+}
+
+/// Glyph widths (in pixels) that can be stored in the generated tables, depending on whether the
+/// `wide-glyphs` feature is enabled.
+pub fn supported_widths(wide_glyphs: bool) -> &'static [usize] {
+    if wide_glyphs {
+        &[8, 16, 24, 32]
+    } else {
+        &[8, 16]
+    }
+}
+
+/// Parse all glyphs of a given height from the contents of a hex font file.
+///
+/// Instead of stopping at the first bad record, every problem is collected and returned, so that
+/// they can all be reported at once. The following are considered problems:
+///
+/// - malformed lines;
+/// - codepoints beyond U+10FFFF;
+/// - glyphs whose width isn’t one of [supported_widths];
+/// - codepoints defined more than once, either with the same width or in both width tables.
+///
+/// On success, the glyphs are returned sorted by codepoint.
+pub fn parse_glyphs(
+    contents: &str,
+    height: usize,
+    wide_glyphs: bool,
+) -> Result<Vec<Glyph>, Vec<String>> {
+    let mut problems = Vec::new();
+    let mut glyphs = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        if line.is_empty() {
+            continue;
+        }
+        let glyph = match Glyph::from_line(line_number, line, height) {
+            Ok(glyph) => glyph,
+            Err(err) => {
+                problems.push(err.to_string());
+                continue;
+            }
+        };
+        if glyph.codepoint > MAX_CODEPOINT {
+            problems.push(format!(
+                "line {line_number}: codepoint {:X} is beyond U+10FFFF",
+                glyph.codepoint
+            ));
+        } else if !supported_widths(wide_glyphs).contains(&glyph.width()) {
+            let hint = if !wide_glyphs && matches!(glyph.width(), 24 | 32) {
+                " (enable the `wide-glyphs` feature to support it)"
+            } else {
+                ""
+            };
+            problems.push(format!(
+                "line {line_number}: U+{:04X} has an unexpected width of {} pixels{hint}",
+                glyph.codepoint,
+                glyph.width()
+            ));
+        } else {
+            glyphs.push(glyph);
+        }
+    }
+
+    // The sort is stable, so duplicates stay in the order they appear in the file.
+    glyphs.sort_by_key(|g| g.codepoint);
+
+    for pair in glyphs.windows(2) {
+        let (first, second) = (&pair[0], &pair[1]);
+        if first.codepoint != second.codepoint {
+            continue;
+        }
+        if first.width() == second.width() {
+            problems.push(format!(
+                "U+{:04X} is defined more than once (lines {} and {})",
+                first.codepoint, first.line_number, second.line_number
+            ));
+        } else {
+            problems.push(format!(
+                "U+{:04X} is present in both the {}x{height} and {}x{height} tables (lines {} and {})",
+                first.codepoint,
+                first.width(),
+                second.width(),
+                first.line_number,
+                second.line_number
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(glyphs)
+    } else {
+        Err(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An 8×16 glyph of the given codepoint, as a hex font line.
+    fn narrow(codepoint: &str) -> String {
+        format!("{codepoint}:{}", "00".repeat(16))
+    }
+
+    /// A 16×16 glyph of the given codepoint, as a hex font line.
+    fn wide(codepoint: &str) -> String {
+        format!("{codepoint}:{}", "00".repeat(32))
+    }
+
+    #[test]
+    fn test_parse_glyphs() {
+        let contents = [narrow("0062"), String::new(), wide("4E00"), narrow("0061")].join("\n");
+        let glyphs = parse_glyphs(&contents, 16, false).unwrap();
+        let summary: Vec<_> = glyphs
+            .iter()
+            .map(|g| (g.codepoint, g.width(), g.line_number))
+            .collect();
+        assert_eq!(summary, [(0x61, 8, 4), (0x62, 8, 1), (0x4E00, 16, 3)]);
+    }
+
+    #[test]
+    fn test_parse_glyphs_duplicates() {
+        let contents = [narrow("0041"), narrow("0042"), narrow("0041")].join("\n");
+        assert_eq!(
+            parse_glyphs(&contents, 16, false).err().unwrap(),
+            ["U+0041 is defined more than once (lines 1 and 3)"]
+        );
+    }
+
+    #[test]
+    fn test_parse_glyphs_overlapping_tables() {
+        let contents = [wide("0041"), narrow("0041")].join("\n");
+        assert_eq!(
+            parse_glyphs(&contents, 16, false).err().unwrap(),
+            ["U+0041 is present in both the 16x16 and 8x16 tables (lines 1 and 2)"]
+        );
+    }
+
+    #[test]
+    fn test_parse_glyphs_beyond_max_codepoint() {
+        let contents = [narrow("0041"), narrow("110000")].join("\n");
+        assert_eq!(
+            parse_glyphs(&contents, 16, false).err().unwrap(),
+            ["line 2: codepoint 110000 is beyond U+10FFFF"]
+        );
+    }
+
+    #[test]
+    fn test_parse_glyphs_malformed_lines() {
+        let contents = ["0041", "XYZ:00", "0042:0G", "0043:"].join("\n");
+        assert_eq!(
+            parse_glyphs(&contents, 16, false).err().unwrap(),
+            [
+                "Invalid line: 1: 0041",
+                "Invalid line: 2: XYZ:00",
+                "Invalid line: 3: 0042:0G",
+                "Invalid line: 4: 0043:",
+            ]
+        );
+        // A bitmap whose length isn’t a multiple of the height is malformed too.
+        assert_eq!(
+            parse_glyphs("0041:00", 16, false).err().unwrap(),
+            ["Invalid line: 1: 0041:00"]
+        );
+    }
+
+    #[test]
+    fn test_parse_glyphs_unsupported_width() {
+        let contents = [
+            format!("0041:{}", "00".repeat(48)),
+            format!("0042:{}", "00".repeat(80)),
+        ]
+        .join("\n");
+        assert_eq!(
+            parse_glyphs(&contents, 16, false).err().unwrap(),
+            [
+                "line 1: U+0041 has an unexpected width of 24 pixels \
+                 (enable the `wide-glyphs` feature to support it)",
+                "line 2: U+0042 has an unexpected width of 40 pixels",
+            ]
+        );
+        assert_eq!(
+            parse_glyphs(&contents, 16, true).err().unwrap(),
+            ["line 2: U+0042 has an unexpected width of 40 pixels"]
+        );
+    }
+}
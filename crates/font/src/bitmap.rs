@@ -16,17 +16,23 @@
 ////////       You should have received a copy of the GNU General Public License           ////////
 ////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
 
+use std::borrow::Cow;
+
 /// Representation of two-dimensional bitmap data.
+///
+/// Pixels are stored one bit each, row by row, with each row padded to a whole number of bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Bitmap {
     pub width: usize,
     pub height: usize,
-    bits: &'static [u8],
+    bits: Cow<'static, [u8]>,
 }
 
 impl Bitmap {
-    /// Create a new [Bitmap] from raw byte data.
-    pub(crate) fn from_raw_data(bits: &'static [u8]) -> Self {
-        let (width, height) = (bits.len() / 2, 16);
+    /// Create a new [Bitmap] with the given dimensions from raw byte data.
+    pub(crate) fn new(width: usize, height: usize, bits: impl Into<Cow<'static, [u8]>>) -> Self {
+        let bits = bits.into();
+        debug_assert_eq!(bits.len(), width.div_ceil(8) * height);
         Self {
             width,
             height,
@@ -34,12 +40,63 @@ impl Bitmap {
         }
     }
 
+    /// Create a new [Bitmap] with the given dimensions and all pixels unset.
+    pub(crate) fn blank(width: usize, height: usize) -> Self {
+        Self::new(width, height, vec![0; width.div_ceil(8) * height])
+    }
+
+    /// Number of bytes used by each row of the bitmap.
+    fn stride(&self) -> usize {
+        self.width.div_ceil(8)
+    }
+
     /// Get the bit at the specified (x, y) coordinate.
+    ///
+    /// Coordinates outside the bitmap are treated as unset pixels.
     pub(crate) fn get_pixel(&self, x: usize, y: usize) -> u8 {
-        let idx = y * (self.width / 8) + (x / 8);
+        if x >= self.width || y >= self.height {
+            return 0;
+        }
+        let idx = y * self.stride() + (x / 8);
         let bit = 7 - (x % 8);
         (self.bits[idx] >> bit) & 1
     }
+
+    /// Set the bit at the specified (x, y) coordinate.
+    ///
+    /// Coordinates outside the bitmap are ignored.
+    pub(crate) fn set_pixel(&mut self, x: usize, y: usize, value: u8) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = y * self.stride() + (x / 8);
+        let mask = 1 << (7 - (x % 8));
+        let bits = self.bits.to_mut();
+        if value != 0 {
+            bits[idx] |= mask;
+        } else {
+            bits[idx] &= !mask;
+        }
+    }
+
+    /// Combine two bitmaps by setting every pixel set in either of them.
+    ///
+    /// The result is as large as the largest of both, with each one centered horizontally.
+    pub(crate) fn overlay(&self, other: &Bitmap) -> Bitmap {
+        let (width, height) = (self.width.max(other.width), self.height.max(other.height));
+        let mut result = Bitmap::blank(width, height);
+        for bitmap in [self, other] {
+            let offset = (width - bitmap.width) / 2;
+            for y in 0..bitmap.height {
+                for x in 0..bitmap.width {
+                    if bitmap.get_pixel(x, y) != 0 {
+                        result.set_pixel(x + offset, y, 1);
+                    }
+                }
+            }
+        }
+        result
+    }
 }
 
 #[cfg(test)]
@@ -49,12 +106,53 @@ mod tests {
 
     #[test]
     fn test_bitmap_dimensions() {
-        let Bitmap { width, height, .. } = Bitmap::from_raw_data(LATIN_SMALL_LETTER_A);
+        let Bitmap { width, height, .. } = Bitmap::new(8, 16, LATIN_SMALL_LETTER_A);
         assert_eq!(width, 8);
         assert_eq!(height, 16);
 
-        let Bitmap { width, height, .. } = Bitmap::from_raw_data(CJK_UNIFIED_IDEOGRAPH_5186);
+        let Bitmap { width, height, .. } = Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186);
         assert_eq!(width, 16);
         assert_eq!(height, 16);
     }
+
+    #[test]
+    fn test_get_pixel() {
+        // 24×2 bitmap: three bytes per row.
+        let bitmap = Bitmap::new(24, 2, &[0x80, 0x00, 0x01, 0x00, 0x18, 0x00]);
+        assert_eq!(bitmap.get_pixel(0, 0), 1);
+        assert_eq!(bitmap.get_pixel(23, 0), 1);
+        assert_eq!(bitmap.get_pixel(11, 1), 1);
+        assert_eq!(bitmap.get_pixel(12, 1), 1);
+        assert_eq!(bitmap.get_pixel(13, 1), 0);
+        // Out of bounds
+        assert_eq!(bitmap.get_pixel(24, 0), 0);
+        assert_eq!(bitmap.get_pixel(0, 2), 0);
+
+        // 5×3 bitmap: rows padded to one byte.
+        let bitmap = Bitmap::new(5, 3, &[0xF8, 0x88, 0xF8]);
+        assert_eq!(bitmap.get_pixel(4, 0), 1);
+        assert_eq!(bitmap.get_pixel(2, 1), 0);
+        assert_eq!(bitmap.get_pixel(4, 1), 1);
+    }
+
+    #[test]
+    fn test_set_pixel() {
+        let mut bitmap = Bitmap::blank(12, 2);
+        bitmap.set_pixel(0, 0, 1);
+        bitmap.set_pixel(11, 1, 1);
+        bitmap.set_pixel(12, 1, 1); // Out of bounds, ignored
+        assert_eq!(bitmap, Bitmap::new(12, 2, vec![0x80, 0x00, 0x00, 0x10]));
+        bitmap.set_pixel(0, 0, 0);
+        assert_eq!(bitmap, Bitmap::new(12, 2, vec![0x00, 0x00, 0x00, 0x10]));
+    }
+
+    #[test]
+    fn test_overlay() {
+        let narrow = Bitmap::new(8, 2, &[0x81, 0x00]);
+        let wide = Bitmap::new(16, 2, &[0x00, 0x00, 0xFF, 0xFF]);
+        assert_eq!(
+            narrow.overlay(&wide),
+            Bitmap::new(16, 2, vec![0x08, 0x10, 0xFF, 0xFF])
+        );
+    }
 }
@@ -57,12 +57,15 @@ fn draw_simple(bitmap: &Bitmap, zero: &str, one: &str) -> String {
 }
 
 /// Draw a [Bitmap] using Unicode block elements. (' ', '▀', '▄', '█')
+///
+/// Each character covers two rows of pixels; if the height is odd, the last row is drawn as if
+/// followed by an empty one.
 fn draw_blocks(bitmap: &Bitmap) -> String {
     let mut result = String::new();
     for y in (0..bitmap.height).step_by(2) {
         for x in 0..bitmap.width {
             let upper = bitmap.get_pixel(x, y) != 0;
-            let lower = bitmap.get_pixel(x, y + 1) != 0;
+            let lower = y + 1 < bitmap.height && bitmap.get_pixel(x, y + 1) != 0;
             result.push(match (upper, lower) {
                 (false, false) => ' ',
                 (false, true) => '▄',
@@ -86,14 +89,14 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::Simple('#'),
-                &Bitmap::from_raw_data(LATIN_SMALL_LETTER_A)
+                &Bitmap::new(8, 16, LATIN_SMALL_LETTER_A)
             ),
             DRAWING_SIMPLE_LATIN_SMALL_LETTER_A.replace('_', " ")
         );
         assert_eq!(
             draw(
                 DrawingMode::Simple('#'),
-                &Bitmap::from_raw_data(CJK_UNIFIED_IDEOGRAPH_5186)
+                &Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186)
             ),
             DRAWING_SIMPLE_CJK_UNIFIED_IDEOGRAPH_5186.replace('_', " "),
         );
@@ -104,7 +107,7 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::Wide('#'),
-                &Bitmap::from_raw_data(LATIN_SMALL_LETTER_A)
+                &Bitmap::new(8, 16, LATIN_SMALL_LETTER_A)
             ),
             DRAWING_SIMPLE_LATIN_SMALL_LETTER_A
                 .replace('#', "##")
@@ -113,7 +116,7 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::Wide('#'),
-                &Bitmap::from_raw_data(CJK_UNIFIED_IDEOGRAPH_5186)
+                &Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186)
             ),
             DRAWING_SIMPLE_CJK_UNIFIED_IDEOGRAPH_5186
                 .replace('#', "##")
@@ -126,7 +129,7 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::Blocks,
-                &Bitmap::from_raw_data(LATIN_SMALL_LETTER_A)
+                &Bitmap::new(8, 16, LATIN_SMALL_LETTER_A)
             ),
             DRAWING_BLOCKS_LATIN_SMALL_LETTER_A.replace('_', " "),
         );
@@ -134,18 +137,24 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::Blocks,
-                &Bitmap::from_raw_data(CJK_UNIFIED_IDEOGRAPH_5186)
+                &Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186)
             ),
             DRAWING_BLOCKS_CJK_UNIFIED_IDEOGRAPH_5186.replace('_', " "),
         );
     }
 
+    #[test]
+    fn test_draw_blocks_odd_height() {
+        let bitmap = Bitmap::new(3, 3, &[0xA0, 0x60, 0xE0]);
+        assert_eq!(draw(DrawingMode::Blocks, &bitmap), "▀▄█\n▀▀▀");
+    }
+
     #[test]
     fn test_draw_wide_blocks() {
         assert_eq!(
             draw(
                 DrawingMode::WideBlocks,
-                &Bitmap::from_raw_data(LATIN_SMALL_LETTER_A)
+                &Bitmap::new(8, 16, LATIN_SMALL_LETTER_A)
             ),
             DRAWING_SIMPLE_LATIN_SMALL_LETTER_A
                 .replace('#', "██")
@@ -154,7 +163,7 @@ mod tests {
         assert_eq!(
             draw(
                 DrawingMode::WideBlocks,
-                &Bitmap::from_raw_data(CJK_UNIFIED_IDEOGRAPH_5186)
+                &Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186)
             ),
             DRAWING_SIMPLE_CJK_UNIFIED_IDEOGRAPH_5186
                 .replace('#', "██")
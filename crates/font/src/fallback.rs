@@ -0,0 +1,341 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::Bitmap;
+use crate::unifont::find_entry;
+
+/// Codepoint of the dotted circle used as a base for combining marks.
+const DOTTED_CIRCLE: u32 = 0x25CC;
+
+/// Highest valid Unicode codepoint.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+/// Size of the generated placeholder glyphs.
+const PLACEHOLDER_SIZE: usize = 16;
+
+/// Class of a codepoint, as far as choosing a glyph fallback is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlyphClass {
+    /// An assigned codepoint that isn’t a combining mark.
+    Graphic,
+    /// A combining mark, which is drawn over a base character.
+    Combining,
+    /// A codepoint not assigned to any character.
+    Unassigned,
+    /// A codepoint in one of the private use areas.
+    PrivateUse,
+    /// A high or low surrogate.
+    Surrogate,
+    /// One of the 66 noncharacters.
+    Noncharacter,
+    /// A value beyond U+10FFFF, which isn’t a codepoint at all.
+    Invalid,
+}
+
+impl GlyphClass {
+    /// Classify a codepoint using only what can be determined from its value.
+    ///
+    /// Combining marks and unassigned codepoints need the Unicode Character Database, so those are
+    /// reported as [GlyphClass::Graphic], and it is up to the caller to refine the class.
+    pub fn from_codepoint(codepoint: u32) -> Self {
+        match codepoint {
+            cp if cp > MAX_CODEPOINT => Self::Invalid,
+            0xD800..=0xDFFF => Self::Surrogate,
+            0xFDD0..=0xFDEF => Self::Noncharacter,
+            cp if cp & 0xFFFE == 0xFFFE => Self::Noncharacter,
+            0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD => Self::PrivateUse,
+            _ => Self::Graphic,
+        }
+    }
+}
+
+/// A step in the chain of fallbacks used when drawing a glyph.
+///
+/// The steps are tried in the order they are given, each one acting only on the codepoints it
+/// applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    /// Draw combining marks over a dotted circle (U+25CC), so that they have a visible base.
+    DottedCircle,
+    /// Draw a placeholder for unassigned, private-use, surrogate and noncharacter codepoints that
+    /// have no glyph, with a frame style distinct for each class.
+    Placeholder,
+    /// Draw a box showing the codepoint in hexadecimal, like Unifont’s own missing glyphs.
+    HexBox,
+}
+
+/// The default chain of fallbacks, which always yields a glyph.
+pub const DEFAULT_FALLBACKS: &[Fallback] = &[
+    Fallback::DottedCircle,
+    Fallback::Placeholder,
+    Fallback::HexBox,
+];
+
+/// Find the glyph for a codepoint, trying the given fallbacks in order.
+///
+/// Values beyond U+10FFFF aren’t codepoints, so no glyph is generated for them whatever the
+/// fallbacks.
+pub(crate) fn find_with_fallbacks(
+    codepoint: u32,
+    class: GlyphClass,
+    fallbacks: &[Fallback],
+) -> Option<Bitmap> {
+    if codepoint > MAX_CODEPOINT {
+        return None;
+    }
+    let mut glyph = find_entry(codepoint);
+    for fallback in fallbacks {
+        glyph = match (fallback, glyph) {
+            (Fallback::DottedCircle, Some(glyph)) if class == GlyphClass::Combining => {
+                match find_entry(DOTTED_CIRCLE) {
+                    Some(circle) => Some(glyph.overlay(&circle)),
+                    None => Some(glyph),
+                }
+            }
+            (Fallback::Placeholder, None) => {
+                Frame::for_class(class).map(|frame| hex_box(codepoint, frame))
+            }
+            (Fallback::HexBox, None) => Some(hex_box(codepoint, Frame::Solid)),
+            (_, glyph) => glyph,
+        };
+    }
+    glyph
+}
+
+/// Style of the frame around a generated glyph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    /// A one pixel wide line, used for missing glyphs.
+    Solid,
+    /// A dotted line, used for unassigned codepoints.
+    Dotted,
+    /// Only the corners, used for private-use codepoints.
+    Corners,
+    /// A dashed line, used for noncharacters.
+    Dashed,
+    /// A filled box with the digits in negative, used for surrogates.
+    Inverted,
+}
+
+impl Frame {
+    /// Frame used for the placeholder of a given class, if any.
+    fn for_class(class: GlyphClass) -> Option<Self> {
+        match class {
+            GlyphClass::Unassigned => Some(Self::Dotted),
+            GlyphClass::PrivateUse => Some(Self::Corners),
+            GlyphClass::Noncharacter => Some(Self::Dashed),
+            GlyphClass::Surrogate => Some(Self::Inverted),
+            GlyphClass::Graphic | GlyphClass::Combining | GlyphClass::Invalid => None,
+        }
+    }
+
+    /// Whether the frame has a pixel at the given (x, y) coordinate of a glyph of a given size.
+    fn has_pixel(self, x: usize, y: usize, size: usize) -> bool {
+        let last = size - 1;
+        let on_edge = x == 0 || y == 0 || x == last || y == last;
+        match self {
+            Self::Solid => on_edge,
+            Self::Dotted => on_edge && (x + y).is_multiple_of(2),
+            Self::Corners => {
+                let near = |v: usize| v < 4 || v > last - 4;
+                on_edge && near(x) && near(y)
+            }
+            Self::Dashed => {
+                let along = if y == 0 || y == last { x } else { y };
+                on_edge && (along / 2).is_multiple_of(2)
+            }
+            Self::Inverted => true,
+        }
+    }
+}
+
+/// Hexadecimal digits, 3 pixels wide and 5 pixels high, one byte per row.
+const DIGITS: [[u8; 5]; 16] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111], // 0
+    [0b010, 0b110, 0b010, 0b010, 0b111], // 1
+    [0b111, 0b001, 0b111, 0b100, 0b111], // 2
+    [0b111, 0b001, 0b111, 0b001, 0b111], // 3
+    [0b101, 0b101, 0b111, 0b001, 0b001], // 4
+    [0b111, 0b100, 0b111, 0b001, 0b111], // 5
+    [0b111, 0b100, 0b111, 0b101, 0b111], // 6
+    [0b111, 0b001, 0b001, 0b001, 0b001], // 7
+    [0b111, 0b101, 0b111, 0b101, 0b111], // 8
+    [0b111, 0b101, 0b111, 0b001, 0b111], // 9
+    [0b111, 0b101, 0b111, 0b101, 0b101], // A
+    [0b110, 0b101, 0b110, 0b101, 0b110], // B
+    [0b111, 0b100, 0b100, 0b100, 0b111], // C
+    [0b110, 0b101, 0b101, 0b101, 0b110], // D
+    [0b111, 0b100, 0b111, 0b100, 0b111], // E
+    [0b111, 0b100, 0b111, 0b100, 0b100], // F
+];
+
+/// Vertical position of each of the two rows of digits in a generated glyph.
+const DIGIT_ROWS: [usize; 2] = [2, 9];
+
+/// Generate a box with the hexadecimal digits of a codepoint inside it.
+///
+/// Codepoints in the BMP are shown as two rows of two digits, others as two rows of three digits.
+fn hex_box(codepoint: u32, frame: Frame) -> Bitmap {
+    let size = PLACEHOLDER_SIZE;
+    let mut bitmap = Bitmap::blank(size, size);
+    for y in 0..size {
+        for x in 0..size {
+            if frame.has_pixel(x, y, size) {
+                bitmap.set_pixel(x, y, 1);
+            }
+        }
+    }
+
+    let digits = if codepoint <= 0xFFFF {
+        format!("{codepoint:04X}")
+    } else {
+        format!("{codepoint:06X}")
+    };
+    let per_row = digits.len() / 2;
+    let row_width = per_row * 4 - 1;
+    let ink = if frame == Frame::Inverted { 0 } else { 1 };
+
+    for (row, chunk) in digits.as_bytes().chunks(per_row).enumerate() {
+        let left = (size - row_width) / 2;
+        for (col, digit) in chunk.iter().enumerate() {
+            let glyph = DIGITS[(*digit as char).to_digit(16).unwrap() as usize];
+            for (dy, bits) in glyph.iter().enumerate() {
+                for dx in 0..3 {
+                    if (bits >> (2 - dx)) & 1 != 0 {
+                        bitmap.set_pixel(left + col * 4 + dx, DIGIT_ROWS[row] + dy, ink);
+                    }
+                }
+            }
+        }
+    }
+
+    bitmap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{DrawingMode, draw};
+    use crate::test_consts::*;
+
+    #[test]
+    fn test_glyph_class() {
+        assert_eq!(GlyphClass::from_codepoint(0x41), GlyphClass::Graphic);
+        assert_eq!(GlyphClass::from_codepoint(0xD800), GlyphClass::Surrogate);
+        assert_eq!(GlyphClass::from_codepoint(0xDFFF), GlyphClass::Surrogate);
+        assert_eq!(GlyphClass::from_codepoint(0xE000), GlyphClass::PrivateUse);
+        assert_eq!(GlyphClass::from_codepoint(0x10FFFD), GlyphClass::PrivateUse);
+        assert_eq!(GlyphClass::from_codepoint(0xFDD0), GlyphClass::Noncharacter);
+        assert_eq!(GlyphClass::from_codepoint(0xFFFE), GlyphClass::Noncharacter);
+        assert_eq!(
+            GlyphClass::from_codepoint(0x10FFFF),
+            GlyphClass::Noncharacter
+        );
+        assert_eq!(
+            GlyphClass::from_codepoint(0x1FFFF),
+            GlyphClass::Noncharacter
+        );
+        assert_eq!(GlyphClass::from_codepoint(0x110000), GlyphClass::Invalid);
+        assert_eq!(GlyphClass::from_codepoint(0x11FFFF), GlyphClass::Invalid);
+        assert_eq!(GlyphClass::from_codepoint(u32::MAX), GlyphClass::Invalid);
+    }
+
+    #[test]
+    fn test_hex_box() {
+        assert_eq!(
+            draw(DrawingMode::Simple('#'), &hex_box(0x0378, Frame::Solid)),
+            DRAWING_HEX_BOX_0378.replace('_', " ")
+        );
+        assert_eq!(
+            draw(DrawingMode::Simple('#'), &hex_box(0x10FFFF, Frame::Dashed)),
+            DRAWING_HEX_BOX_10FFFF_DASHED.replace('_', " ")
+        );
+    }
+
+    #[test]
+    fn test_placeholders_are_distinct() {
+        let frames = [
+            Frame::Solid,
+            Frame::Dotted,
+            Frame::Corners,
+            Frame::Dashed,
+            Frame::Inverted,
+        ];
+        for (i, a) in frames.iter().enumerate() {
+            for b in &frames[i + 1..] {
+                assert_ne!(hex_box(0xE000, *a), hex_box(0xE000, *b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_with_fallbacks() {
+        // Glyphs in the font are returned as they are.
+        assert_eq!(
+            find_with_fallbacks(0x61, GlyphClass::Graphic, DEFAULT_FALLBACKS),
+            Some(Bitmap::new(8, 16, LATIN_SMALL_LETTER_A))
+        );
+        // Without fallbacks, nothing is drawn for missing glyphs.
+        assert_eq!(
+            find_with_fallbacks(0x0378, GlyphClass::Unassigned, &[]),
+            None
+        );
+        // Placeholders only apply to the classes they are meant for.
+        assert_eq!(
+            find_with_fallbacks(0x0378, GlyphClass::Unassigned, &[Fallback::Placeholder]),
+            Some(hex_box(0x0378, Frame::Dotted))
+        );
+        assert_eq!(
+            find_with_fallbacks(0x0378, GlyphClass::Graphic, &[Fallback::Placeholder]),
+            None
+        );
+        // The hex box is the last resort.
+        assert_eq!(
+            find_with_fallbacks(0x0378, GlyphClass::Graphic, DEFAULT_FALLBACKS),
+            Some(hex_box(0x0378, Frame::Solid))
+        );
+        assert_eq!(
+            find_with_fallbacks(0xDB80, GlyphClass::Surrogate, DEFAULT_FALLBACKS),
+            Some(hex_box(0xDB80, Frame::Inverted))
+        );
+        // Values beyond U+10FFFF get no glyph, whatever class they are given.
+        for codepoint in [0x110000, u32::MAX] {
+            for class in [GlyphClass::Invalid, GlyphClass::Noncharacter] {
+                assert_eq!(
+                    find_with_fallbacks(codepoint, class, DEFAULT_FALLBACKS),
+                    None
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dotted_circle() {
+        let mark = find_entry(0x0301).unwrap();
+        let circle = find_entry(DOTTED_CIRCLE).unwrap();
+        assert_eq!(
+            find_with_fallbacks(0x0301, GlyphClass::Combining, &[Fallback::DottedCircle]),
+            Some(mark.overlay(&circle))
+        );
+        // Only combining marks get the dotted circle.
+        assert_eq!(
+            find_with_fallbacks(0x0301, GlyphClass::Graphic, &[Fallback::DottedCircle]),
+            Some(mark)
+        );
+    }
+}
@@ -18,19 +18,41 @@
 
 mod bitmap;
 mod draw;
+mod fallback;
 mod unifont;
 
 #[cfg(test)]
 mod test_consts;
 
+// The parser behind the build script, compiled here so that its tests run with the crate’s.
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../build/glyphs.rs"]
+mod build_glyphs;
+
 pub use draw::DrawingMode;
+pub use fallback::{DEFAULT_FALLBACKS, Fallback, GlyphClass};
 pub use unifont::UNIFONT_VERSION;
 
-use crate::{bitmap::Bitmap, draw::draw, unifont::find_entry};
+use crate::{bitmap::Bitmap, draw::draw, fallback::find_with_fallbacks, unifont::find_entry};
 
 /// Draw the glyph for the specified codepoint, in the specified mode.
 pub fn draw_glyph(codepoint: u32, mode: DrawingMode) -> Option<String> {
-    find_entry(codepoint).map(|raw| draw(mode, &Bitmap::from_raw_data(raw)))
+    find_entry(codepoint).map(|bitmap| draw(mode, &bitmap))
+}
+
+/// Draw the glyph for the specified codepoint, in the specified mode, trying the given chain of
+/// fallbacks when the font has no glyph for it.
+///
+/// The `class` of the codepoint decides which fallbacks apply; [GlyphClass::from_codepoint] gives
+/// a starting point for it. Values beyond U+10FFFF always yield `None`.
+pub fn draw_glyph_with_fallbacks(
+    codepoint: u32,
+    mode: DrawingMode,
+    class: GlyphClass,
+    fallbacks: &[Fallback],
+) -> Option<String> {
+    find_with_fallbacks(codepoint, class, fallbacks).map(|bitmap| draw(mode, &bitmap))
 }
 
 #[cfg(test)]
@@ -49,5 +71,39 @@ mod tests {
             draw_glyph(0x5186, DrawingMode::Blocks),
             Some(DRAWING_BLOCKS_CJK_UNIFIED_IDEOGRAPH_5186.replace('_', " "))
         );
+        assert_eq!(draw_glyph(0x0378, DrawingMode::Blocks), None);
+    }
+
+    #[test]
+    fn test_draw_glyph_with_fallbacks() {
+        assert_eq!(
+            draw_glyph_with_fallbacks(
+                0x61,
+                DrawingMode::Simple('#'),
+                GlyphClass::Graphic,
+                DEFAULT_FALLBACKS
+            ),
+            Some(DRAWING_SIMPLE_LATIN_SMALL_LETTER_A.replace('_', " "))
+        );
+        assert_eq!(
+            draw_glyph_with_fallbacks(
+                0x0378,
+                DrawingMode::Simple('#'),
+                GlyphClass::Graphic,
+                DEFAULT_FALLBACKS
+            ),
+            Some(DRAWING_HEX_BOX_0378.replace('_', " "))
+        );
+        for codepoint in [0x110000, u32::MAX] {
+            assert_eq!(
+                draw_glyph_with_fallbacks(
+                    codepoint,
+                    DrawingMode::Blocks,
+                    GlyphClass::from_codepoint(codepoint),
+                    DEFAULT_FALLBACKS
+                ),
+                None
+            );
+        }
     }
 }
@@ -81,3 +81,39 @@ pub(crate) const DRAWING_BLOCKS_CJK_UNIFIED_IDEOGRAPH_5186: &str = indoc! {"
     _█___________█__
     _█___________█__
     _█_________▀▄▀__"};
+
+pub(crate) const DRAWING_HEX_BOX_0378: &str = indoc! {"
+    ################
+    #______________#
+    #___###_###____#
+    #___#_#___#____#
+    #___#_#_###____#
+    #___#_#___#____#
+    #___###_###____#
+    #______________#
+    #______________#
+    #___###_###____#
+    #_____#_#_#____#
+    #_____#_###____#
+    #_____#_#_#____#
+    #_____#_###____#
+    #______________#
+    ################"};
+
+pub(crate) const DRAWING_HEX_BOX_10FFFF_DASHED: &str = indoc! {"
+    ##__##__##__##__
+    #______________#
+    ___#__###_###___
+    __##__#_#_#_____
+    #__#__#_#_###__#
+    #__#__#_#_#____#
+    __###_###_#_____
+    ________________
+    #______________#
+    #_###_###_###__#
+    __#___#___#_____
+    __###_###_###___
+    #_#___#___#____#
+    #_#___#___#____#
+    ________________
+    ##__##__##__##__"};
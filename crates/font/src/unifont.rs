@@ -16,38 +16,93 @@
 ////////       You should have received a copy of the GNU General Public License           ////////
 ////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
 
+use crate::Bitmap;
+
 /// Search for a glyph entry by its codepoint.
 ///
-/// Returns a reference to a slice of bytes containing the raw bitmap, or `None` if the codepoint
-/// doesn’t exist in the font.
-pub(crate) fn find_entry(codepoint: u32) -> Option<&'static [u8]> {
-    find_entry_internal(codepoint, UNIFONT_GLYPHS_8X16.0)
-        .or_else(|| find_entry_internal(codepoint, UNIFONT_GLYPHS_16X16.0))
+/// Returns the [Bitmap] of the glyph, or `None` if the codepoint doesn’t exist in the font.
+pub(crate) fn find_entry(codepoint: u32) -> Option<Bitmap> {
+    glyph_tables(UNIFONT_GLYPHS).find_map(|table| table.find(codepoint))
+}
+
+/// A table of glyphs sharing the same dimensions.
+struct GlyphTable {
+    width: usize,
+    height: usize,
+    /// Records of the table, each one being a codepoint followed by the bitmap data.
+    records: &'static [u8],
+}
+
+impl GlyphTable {
+    /// Size of the header of each table: width (`u16`), height (`u16`) and record count (`u32`).
+    const HEADER_SIZE: usize = 8;
+
+    /// Size in bytes of each record of the table.
+    fn record_size(&self) -> usize {
+        4 + self.width.div_ceil(8) * self.height
+    }
+
+    /// Number of records in the table.
+    fn len(&self) -> usize {
+        self.records.len() / self.record_size()
+    }
+
+    /// Get the codepoint and bitmap data of the record at the given index.
+    fn record(&self, idx: usize) -> (u32, &'static [u8]) {
+        let record = &self.records[idx * self.record_size()..(idx + 1) * self.record_size()];
+        (
+            u32::from_ne_bytes(record[0..4].try_into().unwrap()),
+            &record[4..],
+        )
+    }
+
+    /// Binary search for a glyph by its codepoint.
+    fn find(&self, codepoint: u32) -> Option<Bitmap> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (record_codepoint, bits) = self.record(mid);
+            match record_codepoint.cmp(&codepoint) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => {
+                    return Some(Bitmap::new(self.width, self.height, bits));
+                }
+            }
+        }
+        None
+    }
 }
 
-/// Recursive binary search for glyph entry in a font.
-fn find_entry_internal<const N: usize>(
-    codepoint: u32,
-    font: &'static [[u8; N]],
-) -> Option<&'static [u8]> {
-    let idx = font
-        .binary_search_by_key(&codepoint, |record| {
-            u32::from_ne_bytes(record[0..4].try_into().unwrap())
-        })
-        .ok()?;
-    Some(&font[idx][4..])
+/// Iterate over the glyph tables stored in a font file.
+fn glyph_tables(mut data: &'static [u8]) -> impl Iterator<Item = GlyphTable> {
+    std::iter::from_fn(move || {
+        if data.len() < GlyphTable::HEADER_SIZE {
+            return None;
+        }
+        let width = u16::from_ne_bytes(data[0..2].try_into().unwrap()) as usize;
+        let height = u16::from_ne_bytes(data[2..4].try_into().unwrap()) as usize;
+        let count = u32::from_ne_bytes(data[4..8].try_into().unwrap()) as usize;
+        let mut table = GlyphTable {
+            width,
+            height,
+            records: &[],
+        };
+        let end = GlyphTable::HEADER_SIZE + count * table.record_size();
+        table.records = &data[GlyphTable::HEADER_SIZE..end];
+        data = &data[end..];
+        Some(table)
+    })
 }
 
 /// Version of the included Unifont font.
 pub const UNIFONT_VERSION: &str = include_str!(env!("UNIFONT_VERSION_FILE"));
 
-// These fonts are formatted as records of either 4 + 16 or 4 + 32 bytes, the first 4 being the
-// codepoint and the rest being the bitmap data. The records are sorted by codepoint, so we can
-// use binary search to find the bitmap for a given codepoint.
-const UNIFONT_GLYPHS_8X16: (&[[u8; 20]], &[u8]) =
-    include_bytes!(env!("UNIFONT_GLYPHS_8X16_FILE")).as_chunks::<20>();
-const UNIFONT_GLYPHS_16X16: (&[[u8; 36]], &[u8]) =
-    include_bytes!(env!("UNIFONT_GLYPHS_16X16_FILE")).as_chunks::<36>();
+// The font is formatted as a sequence of tables, one for each glyph width. Each table starts with
+// a header holding the width and height of its glyphs and the number of records, followed by the
+// records, each one being 4 bytes of codepoint and the bitmap data. The records are sorted by
+// codepoint, so we can use binary search to find the bitmap for a given codepoint.
+const UNIFONT_GLYPHS: &[u8] = include_bytes!(env!("UNIFONT_GLYPHS_FILE"));
 
 #[cfg(test)]
 mod tests {
@@ -56,16 +111,62 @@ mod tests {
 
     #[test]
     fn test_find_entry() {
-        assert_eq!(find_entry(0x20).unwrap(), &[0; 16]);
-        assert_eq!(find_entry(0x0061).unwrap(), LATIN_SMALL_LETTER_A);
-        assert_eq!(find_entry(0x3000).unwrap(), &[0; 32]);
-        assert_eq!(find_entry(0x5186).unwrap(), CJK_UNIFIED_IDEOGRAPH_5186);
+        assert_eq!(find_entry(0x20).unwrap(), Bitmap::new(8, 16, &[0; 16]));
+        assert_eq!(
+            find_entry(0x0061).unwrap(),
+            Bitmap::new(8, 16, LATIN_SMALL_LETTER_A)
+        );
+        assert_eq!(find_entry(0x3000).unwrap(), Bitmap::new(16, 16, &[0; 32]));
+        assert_eq!(
+            find_entry(0x5186).unwrap(),
+            Bitmap::new(16, 16, CJK_UNIFIED_IDEOGRAPH_5186)
+        );
         assert!(find_entry(0x110000).is_none());
     }
 
     #[test]
-    fn test_font_record_size() {
-        assert_eq!(UNIFONT_GLYPHS_8X16.1.len(), 0);
-        assert_eq!(UNIFONT_GLYPHS_16X16.1.len(), 0);
+    fn test_glyph_tables() {
+        let tables: Vec<GlyphTable> = glyph_tables(UNIFONT_GLYPHS).collect();
+        assert!(tables.iter().any(|t| (t.width, t.height) == (8, 16)));
+        assert!(tables.iter().any(|t| (t.width, t.height) == (16, 16)));
+        let size: usize = tables
+            .iter()
+            .map(|t| GlyphTable::HEADER_SIZE + t.records.len())
+            .sum();
+        assert_eq!(size, UNIFONT_GLYPHS.len());
+        for table in tables {
+            assert_eq!(table.records.len() % table.record_size(), 0);
+        }
+    }
+
+    #[test]
+    fn test_glyph_table_format() {
+        // One 8×2 table with two records, followed by one 12×1 table with one record.
+        let mut data = Vec::new();
+        for (width, height, records) in [
+            (
+                8u16,
+                2u16,
+                vec![(0x41u32, [0xFF, 0x00]), (0x42, [0x00, 0x81])],
+            ),
+            (12, 1, vec![(0x43, [0xF0, 0x10])]),
+        ] {
+            data.extend_from_slice(&width.to_ne_bytes());
+            data.extend_from_slice(&height.to_ne_bytes());
+            data.extend_from_slice(&(records.len() as u32).to_ne_bytes());
+            for (codepoint, bits) in records {
+                data.extend_from_slice(&codepoint.to_ne_bytes());
+                data.extend_from_slice(&bits);
+            }
+        }
+        let tables: Vec<GlyphTable> = glyph_tables(data.leak()).collect();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].find(0x41), Some(Bitmap::new(8, 2, &[0xFF, 0x00])));
+        assert_eq!(tables[0].find(0x42), Some(Bitmap::new(8, 2, &[0x00, 0x81])));
+        assert_eq!(tables[0].find(0x43), None);
+        assert_eq!(
+            tables[1].find(0x43),
+            Some(Bitmap::new(12, 1, &[0xF0, 0x10]))
+        );
     }
 }
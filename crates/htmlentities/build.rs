@@ -31,7 +31,7 @@ fn load_data(data_path: &Path) -> anyhow::Result<Vec<(u32, Vec<String>)>> {
 
         let entity = entity.trim_start_matches('&').trim_end_matches(';');
 
-        map.entry(codepoint).or_insert_with(|| HashSet::new());
+        map.entry(codepoint).or_default();
         map.entry(codepoint).and_modify(|m| {
             m.insert(entity.to_string());
         });
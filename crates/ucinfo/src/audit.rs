@@ -0,0 +1,158 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Security audit of a string, shown with `--audit`: the scripts it mixes, its restriction level
+//! and the characters used in spoofing attacks (UTS #39).
+
+use unicodedata::{CharacterFlag, ScriptSet, StringAnalysis, analyze_string, identifier_types};
+
+use crate::describe::format_codepoint;
+use crate::layout::{Row, format_rows};
+use crate::listing::listing_line;
+
+/// Spaces before the characters listed in each section.
+const LIST_INDENT: &str = "    ";
+
+/// The string, quoted, with control characters and invisible characters replaced by their
+/// codepoints, so that it can be printed safely. Homoglyphs are left alone.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for ch in text.chars() {
+        let codepoint = u32::from(ch);
+        let flag = CharacterFlag::of(codepoint);
+        if ch.is_control() || flag.is_some_and(|flag| flag != CharacterFlag::Homoglyph) {
+            escaped.push_str(&format!("<{}>", format_codepoint(codepoint)));
+        } else {
+            escaped.push(ch);
+        }
+    }
+    format!("'{escaped}'")
+}
+
+/// Describe the resolved script set of a string.
+fn resolved_scripts(analysis: &StringAnalysis) -> String {
+    match &analysis.resolved_scripts {
+        ScriptSet::All => "All (only characters common to all scripts)".to_string(),
+        ScriptSet::Only(scripts) if scripts.is_empty() => "None (mixed-script)".to_string(),
+        ScriptSet::Only(scripts) => scripts
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// Audit a string, e.g. a user name: the scripts of its characters and the scripts they resolve
+/// to, its restriction level, and the characters that are restricted in identifiers or can be
+/// used to hide or disguise text.
+pub(crate) fn audit(text: &str) -> Vec<String> {
+    let analysis = analyze_string(text);
+    let count = text.chars().count();
+    let plural = if count == 1 { "" } else { "s" };
+    let scripts: Vec<String> = analysis.scripts.iter().map(ToString::to_string).collect();
+    let rows = [
+        Row::Text(format!("Audit of {}", escape(text))),
+        Row::Text(format!("{count} character{plural}")),
+        Row::Blank,
+        Row::field("Scripts", scripts.join(", ")),
+        Row::field("Resolved Scripts", resolved_scripts(&analysis)),
+        Row::field("Restriction Level", analysis.restriction_level.name()),
+    ];
+    let mut lines = format_rows(&rows);
+    if !analysis.restricted.is_empty() {
+        lines.extend([
+            String::new(),
+            "Restricted characters:".to_string(),
+            String::new(),
+        ]);
+        lines.extend(analysis.restricted.iter().map(|&cp| {
+            let types: Vec<&str> = identifier_types(cp).into_iter().map(|t| t.name()).collect();
+            format!("{LIST_INDENT}{} ({})", listing_line(cp), types.join(", "))
+        }));
+    }
+    if !analysis.flagged.is_empty() {
+        lines.extend([
+            String::new(),
+            "Suspicious characters:".to_string(),
+            String::new(),
+        ]);
+        lines.extend(analysis.flagged.iter().map(|flagged| {
+            format!(
+                "{LIST_INDENT}{} ({}, at byte {})",
+                listing_line(flagged.codepoint),
+                flagged.flag.name(),
+                flagged.offset
+            )
+        }));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "'abc'");
+        assert_eq!(escape("a\u{202E}b\tc"), "'a<U+202E>b<U+0009>c'");
+        assert_eq!(escape("pаypal"), "'pаypal'");
+    }
+
+    #[test]
+    fn test_audit() {
+        let lines = audit("pаypal");
+        assert_eq!(
+            lines,
+            [
+                "Audit of 'pаypal'",
+                "6 characters",
+                "",
+                "          Scripts:  Latin (Latn), Cyrillic (Cyrl)",
+                " Resolved Scripts:  None (mixed-script)",
+                "Restriction Level:  Minimally Restrictive",
+                "",
+                "Suspicious characters:",
+                "",
+                "    U+0430    'а'   CYRILLIC SMALL LETTER A (Homoglyph, at byte 1)",
+            ]
+        );
+        assert_eq!(
+            audit("123")[4],
+            " Resolved Scripts:  All (only characters common to all scripts)"
+        );
+    }
+
+    #[test]
+    fn test_audit_sections() {
+        let lines = audit("a\u{202E}b");
+        assert_eq!(lines[0], "Audit of 'a<U+202E>b'");
+        assert_eq!(lines[5], "Restriction Level:  Unrestricted");
+        assert_eq!(lines[7], "Restricted characters:");
+        assert_eq!(
+            lines[9],
+            format!("    {} (Default Ignorable)", listing_line(0x202E))
+        );
+        assert_eq!(lines[11], "Suspicious characters:");
+        assert_eq!(
+            lines[13],
+            format!("    {} (Bidi Control, at byte 1)", listing_line(0x202E))
+        );
+        assert_eq!(lines.len(), 14);
+    }
+}
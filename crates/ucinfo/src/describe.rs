@@ -0,0 +1,956 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use unicodedata::{
+    AmbiguousWidth, BinaryProperty, GeneralCategory, PairedBracketType, Plane, age, bidi_class,
+    bidi_mirroring_glyph, binary_properties, block_of, canonical_decomposition, case_fold,
+    compatibility_decomposition, confusables, decomposition, display_name, display_width,
+    emoji_sequence, general_category, grapheme_cluster_break, hangul_syllable, has_property,
+    is_assigned, is_bidi_mirrored, jamo_short_name, line_break, lowercase, numeric_type,
+    numeric_value, paired_bracket, paired_bracket_type, romanize, script, script_extensions,
+    sentence_break, simple_case_fold, simple_lowercase, simple_titlecase, simple_uppercase,
+    titlecase, uppercase, word_break,
+};
+use unifont::{DEFAULT_FALLBACKS, DrawingMode, GlyphClass, draw_glyph_with_fallbacks};
+
+use crate::layout::{Row, format_rows, side_by_side, text_width, wrap_list};
+use crate::listing::listing_line;
+
+/// Spaces between the glyph and the information about the character.
+const GLYPH_GAP: usize = 4;
+
+/// Maximum width of the lines listing the binary properties, not counting the indentation.
+const PROPERTIES_WIDTH: usize = 72;
+
+/// Maximum width of a row of glyphs shown side by side, including their indentation.
+const GLYPH_ROW_WIDTH: usize = 80;
+
+/// Spaces before the glyph.
+const GLYPH_INDENT: &str = "    ";
+
+/// Options controlling how characters are described.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DescribeOptions {
+    /// Whether to draw the glyph of the character.
+    pub draw: bool,
+    /// Whether to show all available information, including the binary properties.
+    pub all: bool,
+}
+
+/// Describe a codepoint, returning the lines to be printed.
+pub(crate) fn describe(codepoint: u32, options: &DescribeOptions) -> Vec<String> {
+    let mut rows = vec![
+        Row::Text(heading(codepoint)),
+        Row::Text(display_name(codepoint)),
+        Row::Blank,
+        Row::field("Unicode Version", version(codepoint)),
+        Row::field("Block", block(codepoint)),
+        Row::field("Plane", plane(codepoint)),
+        Row::field("Script", script(codepoint).to_string()),
+        Row::field("Category", category(codepoint)),
+    ];
+    rows.extend(extensions(codepoint));
+    rows.extend(decomposition_fields(codepoint));
+    rows.extend(romanization(codepoint));
+    #[cfg(feature = "unihan")]
+    rows.extend(unihan_fields(codepoint));
+    rows.extend(case_fields(codepoint));
+    rows.extend(numeric(codepoint));
+    rows.push(Row::field("Spacing", spacing(codepoint)));
+    rows.extend(bidi_fields(codepoint));
+    if options.all {
+        rows.extend(break_fields(codepoint));
+    }
+    rows.extend(encodings(codepoint));
+    rows.push(Row::field(
+        "HTML",
+        htmlentities::get_entities(codepoint).join("  "),
+    ));
+    let info = format_rows(&rows);
+
+    let mut lines = if options.draw {
+        // The glyph starts one line below the heading.
+        let glyph: Vec<String> = std::iter::once(String::new())
+            .chain(glyph_lines(codepoint))
+            .collect();
+        side_by_side(&glyph, &info, GLYPH_GAP)
+    } else {
+        info
+    };
+    lines.extend(components(codepoint, options));
+    lines.extend(mirrored_glyph(codepoint, options));
+    lines.extend(confusable_with(codepoint, options));
+    if options.all {
+        lines.extend(properties(codepoint));
+    }
+    lines
+}
+
+/// Describe a grapheme cluster made of more than one codepoint, listing its codepoints before
+/// they are described one by one. Emoji sequences are named after their CLDR short names.
+pub(crate) fn describe_cluster(cluster: &[u32]) -> Vec<String> {
+    let (title, subtitle) = match emoji_sequence(cluster) {
+        Some(sequence) => (sequence.kind.name(), sequence.name.to_string()),
+        None => ("Grapheme Cluster", format!("{} codepoints", cluster.len())),
+    };
+    let text: Option<String> = cluster.iter().map(|&cp| char::from_u32(cp)).collect();
+    let heading = match text.filter(|text| !text.chars().any(char::is_control)) {
+        Some(text) => format!("{title} '{text}'"),
+        None => title.to_string(),
+    };
+    let mut lines = vec![heading, subtitle, String::new()];
+    lines.extend(
+        cluster
+            .iter()
+            .map(|&cp| format!("{GLYPH_INDENT}{}", listing_line(cp))),
+    );
+    lines
+}
+
+/// Lines of the glyph of a codepoint, drawn with block characters.
+pub(crate) fn glyph(codepoint: u32) -> Vec<String> {
+    draw_glyph_with_fallbacks(
+        codepoint,
+        DrawingMode::Blocks,
+        glyph_class(codepoint),
+        DEFAULT_FALLBACKS,
+    )
+    .unwrap_or_default()
+    .lines()
+    .map(str::to_string)
+    .collect()
+}
+
+/// Lines of the glyph of a codepoint, indented.
+fn glyph_lines(codepoint: u32) -> Vec<String> {
+    glyph(codepoint)
+        .iter()
+        .map(|line| format!("{GLYPH_INDENT}{line}"))
+        .collect()
+}
+
+/// Glyphs of several codepoints side by side, broken into rows no wider than [`GLYPH_ROW_WIDTH`],
+/// separated by empty lines.
+fn glyph_rows(codepoints: &[u32]) -> Vec<String> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    for &cp in codepoints {
+        let glyph = glyph_lines(cp);
+        let joined = side_by_side(&row, &glyph, 0);
+        if !row.is_empty() && joined.iter().any(|line| text_width(line) > GLYPH_ROW_WIDTH) {
+            rows.push(std::mem::replace(&mut row, glyph));
+        } else {
+            row = joined;
+        }
+    }
+    rows.push(row);
+    rows.join(&String::new())
+}
+
+/// Section showing each component of the full decomposition of a character, with its glyph and
+/// name. Characters without a decomposition have no such section. For Hangul syllables, the
+/// components are the jamo, listed with their short names.
+fn components(codepoint: u32, options: &DescribeOptions) -> Vec<String> {
+    let Some(components) = full_decomposition(codepoint) else {
+        return Vec::new();
+    };
+    let is_syllable = hangul_syllable(codepoint).is_some();
+    let title = if is_syllable { "Jamo:" } else { "Components:" };
+    let mut lines = vec![String::new(), title.to_string(), String::new()];
+    if options.draw {
+        lines.extend(glyph_rows(&components));
+        lines.push(String::new());
+    }
+    lines.extend(components.iter().map(|&cp| {
+        let mut line = format!("{GLYPH_INDENT}{}", listing_line(cp));
+        if is_syllable {
+            line.push_str(&format!(" ({})", jamo_short_name(cp).unwrap_or("silent")));
+        }
+        line
+    }));
+    lines
+}
+
+/// Section listing the characters that a character can be mistaken for (UTS #39), with their
+/// glyphs drawn next to its own. Characters without confusables have no such section.
+fn confusable_with(codepoint: u32, options: &DescribeOptions) -> Vec<String> {
+    let confusables = confusables(codepoint);
+    if confusables.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![String::new(), "Confusable with:".to_string(), String::new()];
+    if options.draw {
+        let glyphs: Vec<u32> = std::iter::once(codepoint)
+            .chain(confusables.iter().copied())
+            .collect();
+        lines.extend(glyph_rows(&glyphs));
+        lines.push(String::new());
+    }
+    lines.extend(
+        confusables
+            .iter()
+            .map(|&cp| format!("{GLYPH_INDENT}{}", listing_line(cp))),
+    );
+    lines
+}
+
+/// Section showing the glyph of a character next to the glyph of its mirrored counterpart. It is
+/// only shown when drawing, and for characters that have such a counterpart.
+fn mirrored_glyph(codepoint: u32, options: &DescribeOptions) -> Vec<String> {
+    let Some(mirrored) = bidi_mirroring_glyph(codepoint).filter(|_| options.draw) else {
+        return Vec::new();
+    };
+    let mut lines = vec![String::new(), "Mirrored Glyph:".to_string(), String::new()];
+    lines.extend(side_by_side(
+        &glyph_lines(codepoint),
+        &glyph_lines(mirrored),
+        0,
+    ));
+    lines
+}
+
+/// Section listing the binary properties of a character. Characters without any have no such
+/// section.
+fn properties(codepoint: u32) -> Vec<String> {
+    let names: Vec<&str> = binary_properties(codepoint)
+        .into_iter()
+        .map(BinaryProperty::name)
+        .collect();
+    if names.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![String::new(), "Properties:".to_string(), String::new()];
+    lines.extend(
+        wrap_list(&names, PROPERTIES_WIDTH)
+            .into_iter()
+            .map(|line| format!("{GLYPH_INDENT}{line}")),
+    );
+    lines
+}
+
+/// Class of the codepoint for drawing purposes, refined with its general category.
+fn glyph_class(codepoint: u32) -> GlyphClass {
+    match GlyphClass::from_codepoint(codepoint) {
+        GlyphClass::Graphic if !is_assigned(codepoint) => GlyphClass::Unassigned,
+        GlyphClass::Graphic => match general_category(codepoint) {
+            GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark => GlyphClass::Combining,
+            _ => GlyphClass::Graphic,
+        },
+        class => class,
+    }
+}
+
+/// Format the codepoint as `U+XXXX`.
+pub(crate) fn format_codepoint(codepoint: u32) -> String {
+    format!("U+{codepoint:04X}")
+}
+
+/// Format a range of codepoints as `U+XXXX–U+YYYY`.
+pub(crate) fn format_range(first: u32, last: u32) -> String {
+    format!("{}–{}", format_codepoint(first), format_codepoint(last))
+}
+
+/// The character itself, quoted, if it is safe to print it. Bidi controls are not, as they would
+/// reorder the rest of the line.
+pub(crate) fn printable(codepoint: u32) -> Option<String> {
+    let ch = char::from_u32(codepoint)?;
+    if ch.is_control() || has_property(codepoint, BinaryProperty::BidiControl) {
+        return None;
+    }
+    Some(format!("'{ch}'"))
+}
+
+/// Heading line, with the codepoint and the character itself.
+fn heading(codepoint: u32) -> String {
+    match printable(codepoint) {
+        Some(ch) => format!("{} {ch}", format_codepoint(codepoint)),
+        None => format_codepoint(codepoint),
+    }
+}
+
+/// Version of Unicode in which the character was assigned, with its release date.
+fn version(codepoint: u32) -> String {
+    match age(codepoint) {
+        Some(version) => match version.release_date() {
+            Some(date) => format!("{version} ({date})"),
+            None => version.to_string(),
+        },
+        None => "Unassigned".to_string(),
+    }
+}
+
+/// Name and range of the block the character belongs to.
+fn block(codepoint: u32) -> String {
+    match block_of(codepoint) {
+        Some(block) => format!("{} ({})", block.name, format_range(block.first, block.last)),
+        None => "No Block".to_string(),
+    }
+}
+
+/// Name and range of the plane the character belongs to.
+fn plane(codepoint: u32) -> String {
+    match Plane::of(codepoint) {
+        Some(plane) => format!("{plane} ({})", format_range(plane.first(), plane.last())),
+        None => String::new(),
+    }
+}
+
+/// Name and abbreviation of the general category of the character.
+fn category(codepoint: u32) -> String {
+    let gc = general_category(codepoint);
+    format!("{} ({})", gc.name(), gc.abbr())
+}
+
+/// Field with the script extensions of the character, if they differ from its script.
+fn extensions(codepoint: u32) -> Option<Row> {
+    let extensions = script_extensions(codepoint);
+    if extensions == [script(codepoint)] {
+        return None;
+    }
+    let extensions: Vec<String> = extensions.iter().map(ToString::to_string).collect();
+    Some(Row::field("Script Extensions", extensions.join(", ")))
+}
+
+/// Format a sequence of codepoints, each with the character itself, joined by `+`.
+fn format_sequence(codepoints: &[u32]) -> String {
+    codepoints
+        .iter()
+        .map(|&cp| heading(cp))
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Fields with the decomposition mapping of the character, its type, and its full expansion when
+/// the mapping can be decomposed further.
+fn decomposition_fields(codepoint: u32) -> Vec<Row> {
+    let Some(decomposition) = decomposition(codepoint) else {
+        return Vec::new();
+    };
+    let mut rows = vec![Row::field(
+        "Decomposition",
+        format!(
+            "{} ({})",
+            format_sequence(&decomposition.mapping),
+            decomposition.kind.name()
+        ),
+    )];
+    let full = full_decomposition(codepoint).unwrap_or_default();
+    if full != decomposition.mapping {
+        rows.push(Row::field("Full Decomposition", format_sequence(&full)));
+    }
+    rows
+}
+
+/// Full expansion of the decomposition of a character: canonical if its mapping is canonical,
+/// otherwise compatibility. Characters without a decomposition have none.
+fn full_decomposition(codepoint: u32) -> Option<Vec<u32>> {
+    let decomposition = decomposition(codepoint)?;
+    if decomposition.kind.is_canonical() {
+        Some(canonical_decomposition(codepoint))
+    } else {
+        Some(compatibility_decomposition(codepoint))
+    }
+}
+
+/// Field with the Revised Romanization of a Hangul syllable.
+fn romanization(codepoint: u32) -> Option<Row> {
+    hangul_syllable(codepoint)?;
+    let text = char::from_u32(codepoint)?.to_string();
+    Some(Row::field("Romanization", romanize(&text)))
+}
+
+/// Fields with the readings, definition and stroke counts of an ideograph from the Unihan
+/// database.
+#[cfg(feature = "unihan")]
+fn unihan_fields(codepoint: u32) -> Vec<Row> {
+    let Some(unihan) = unicodedata::unihan(codepoint) else {
+        return Vec::new();
+    };
+    let readings = [
+        ("Mandarin", &unihan.mandarin),
+        ("Cantonese", &unihan.cantonese),
+        ("Japanese On", &unihan.japanese_on),
+        ("Japanese Kun", &unihan.japanese_kun),
+        ("Korean", &unihan.hangul),
+    ];
+    let mut rows: Vec<Row> = unihan
+        .definition
+        .map(|definition| Row::field("Definition", definition.to_string()))
+        .into_iter()
+        .collect();
+    rows.extend(
+        readings
+            .into_iter()
+            .filter(|(_, values)| !values.is_empty())
+            .map(|(label, values)| Row::field(label, values.join(", "))),
+    );
+    if let Some(strokes) = unihan.total_strokes {
+        rows.push(Row::field("Total Strokes", strokes.to_string()));
+    }
+    if !unihan.radical_stroke.is_empty() {
+        let values: Vec<String> = unihan
+            .radical_stroke
+            .iter()
+            .map(|rs| match rs.radical_character() {
+                Some(radical) => format!("{rs} ({radical} + {})", rs.strokes),
+                None => rs.to_string(),
+            })
+            .collect();
+        rows.push(Row::field("Radical-Stroke", values.join(", ")));
+    }
+    rows
+}
+
+/// Fields with the case mappings of the character that map it to something else.
+///
+/// The full mapping is shown, followed by the simple (single codepoint) mapping when it differs
+/// from both the full mapping and the character itself.
+fn case_fields(codepoint: u32) -> Vec<Row> {
+    type Mapping = (&'static str, fn(u32) -> Vec<u32>, fn(u32) -> u32);
+    const MAPPINGS: [Mapping; 4] = [
+        ("Uppercase", uppercase, simple_uppercase),
+        ("Lowercase", lowercase, simple_lowercase),
+        ("Titlecase", titlecase, simple_titlecase),
+        ("Case Folding", case_fold, simple_case_fold),
+    ];
+    MAPPINGS
+        .iter()
+        .filter_map(|&(label, full, simple)| {
+            let full = full(codepoint);
+            let simple = simple(codepoint);
+            if full == [codepoint] && simple == codepoint {
+                return None;
+            }
+            let mut value = format_sequence(&full);
+            if simple != codepoint && full != [simple] {
+                value.push_str(&format!(" (simple: {})", heading(simple)));
+            }
+            Some(Row::field(label, value))
+        })
+        .collect()
+}
+
+/// Field with the numeric value of the character and its numeric type, if it has one.
+fn numeric(codepoint: u32) -> Option<Row> {
+    let value = numeric_value(codepoint)?;
+    let value = match numeric_type(codepoint) {
+        Some(kind) => format!("{value} ({})", kind.name()),
+        None => value.to_string(),
+    };
+    Some(Row::field("Numeric Value", value))
+}
+
+/// Fields with the bidirectional properties of the character: its bidi class, its mirrored
+/// counterpart, and the bracket it pairs with.
+fn bidi_fields(codepoint: u32) -> Vec<Row> {
+    let class = bidi_class(codepoint);
+    let mut rows = vec![Row::field(
+        "Direction",
+        format!("{} ({})", class.name(), class.abbr()),
+    )];
+    if is_bidi_mirrored(codepoint) {
+        let mirrored = match bidi_mirroring_glyph(codepoint) {
+            Some(mirrored) => heading(mirrored),
+            None => "Yes (no mirrored character)".to_string(),
+        };
+        rows.push(Row::field("Mirrored", mirrored));
+    }
+    if let Some(pair) = paired_bracket(codepoint) {
+        let side = match paired_bracket_type(codepoint) {
+            PairedBracketType::Open => "Opening",
+            PairedBracketType::Close => "Closing",
+            PairedBracketType::None => "Unpaired",
+        };
+        rows.push(Row::field(
+            "Bracket",
+            format!("{side}, paired with {}", heading(pair)),
+        ));
+    }
+    rows
+}
+
+/// Fields with the classes of the character for line breaking and text segmentation.
+fn break_fields(codepoint: u32) -> Vec<Row> {
+    let lb = line_break(codepoint);
+    let wb = word_break(codepoint);
+    let sb = sentence_break(codepoint);
+    let gcb = grapheme_cluster_break(codepoint);
+    vec![
+        Row::field("Line Break", format!("{} ({})", lb.name(), lb.abbr())),
+        Row::field("Word Break", format!("{} ({})", wb.name(), wb.abbr())),
+        Row::field("Sentence Break", format!("{} ({})", sb.name(), sb.abbr())),
+        Row::field("Grapheme Break", format!("{} ({})", gcb.name(), gcb.abbr())),
+    ]
+}
+
+/// Describe whether the character advances the cursor, and by how many columns.
+fn spacing(codepoint: u32) -> String {
+    let narrow = display_width(codepoint, AmbiguousWidth::Narrow);
+    let wide = display_width(codepoint, AmbiguousWidth::Wide);
+    match (narrow, wide) {
+        (0, _) => "No (zero width)".to_string(),
+        (1, 2) => "Yes (1 column, 2 in East Asian contexts)".to_string(),
+        (1, _) => "Yes (1 column)".to_string(),
+        (n, _) => format!("Yes ({n} columns)"),
+    }
+}
+
+/// Format bytes as space-separated hexadecimal pairs.
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fields with the UTF-8, UTF-16 and UTF-32 encodings of the codepoint.
+///
+/// Surrogates can’t be encoded, so they have no such fields.
+fn encodings(codepoint: u32) -> Vec<Row> {
+    let Some(ch) = char::from_u32(codepoint) else {
+        return Vec::new();
+    };
+    let mut utf8 = [0; 4];
+    let mut utf16 = [0; 2];
+    let utf16: Vec<u8> = ch
+        .encode_utf16(&mut utf16)
+        .iter()
+        .flat_map(|unit| unit.to_be_bytes())
+        .collect();
+    vec![
+        Row::field("UTF-8", hex_bytes(ch.encode_utf8(&mut utf8).as_bytes())),
+        Row::field("UTF-16", hex_bytes(&utf16)),
+        Row::field("UTF-32", hex_bytes(&codepoint.to_be_bytes())),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(heading(0x41), "U+0041 'A'");
+        assert_eq!(heading(0x1F44D), "U+1F44D '👍'");
+        assert_eq!(heading(0x0A), "U+000A");
+        assert_eq!(heading(0xD800), "U+D800");
+        assert_eq!(heading(0x202E), "U+202E");
+    }
+
+    #[test]
+    fn test_glyph_class() {
+        assert_eq!(glyph_class(0x41), GlyphClass::Graphic);
+        assert_eq!(glyph_class(0x0301), GlyphClass::Combining);
+        assert_eq!(glyph_class(0x0378), GlyphClass::Unassigned);
+        assert_eq!(glyph_class(0xFFFF), GlyphClass::Noncharacter);
+        assert_eq!(glyph_class(0xE000), GlyphClass::PrivateUse);
+    }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(category(0x41), "Uppercase Letter (Lu)");
+        assert_eq!(category(0x0378), "Unassigned (Cn)");
+    }
+
+    #[test]
+    fn test_version() {
+        assert_eq!(version(0xC1), "1.1 (June 1993)");
+        assert_eq!(version(0x1F44D), "6.0 (October 2010)");
+        assert_eq!(version(0x0378), "Unassigned");
+    }
+
+    #[test]
+    fn test_decomposition_fields() {
+        assert_eq!(decomposition_fields(0x41), []);
+        assert_eq!(
+            decomposition_fields(0xC1),
+            [Row::field(
+                "Decomposition",
+                "U+0041 'A' + U+0301 '\u{301}' (Canonical)"
+            )]
+        );
+        assert_eq!(
+            decomposition_fields(0x1EA5),
+            [
+                Row::field("Decomposition", "U+00E2 'â' + U+0301 '\u{301}' (Canonical)"),
+                Row::field(
+                    "Full Decomposition",
+                    "U+0061 'a' + U+0302 '\u{302}' + U+0301 '\u{301}'"
+                ),
+            ]
+        );
+        assert_eq!(
+            decomposition_fields(0xB2),
+            [Row::field("Decomposition", "U+0032 '2' (Superscript)")]
+        );
+    }
+
+    #[test]
+    fn test_case_fields() {
+        assert_eq!(case_fields(0x31), []);
+        assert_eq!(
+            case_fields(0x61),
+            [
+                Row::field("Uppercase", "U+0041 'A'"),
+                Row::field("Titlecase", "U+0041 'A'"),
+            ]
+        );
+        assert_eq!(
+            case_fields(0xDF),
+            [
+                Row::field("Uppercase", "U+0053 'S' + U+0053 'S'"),
+                Row::field("Titlecase", "U+0053 'S' + U+0073 's'"),
+                Row::field("Case Folding", "U+0073 's' + U+0073 's'"),
+            ]
+        );
+        assert_eq!(
+            case_fields(0x130),
+            [
+                Row::field(
+                    "Lowercase",
+                    "U+0069 'i' + U+0307 '\u{307}' (simple: U+0069 'i')"
+                ),
+                Row::field("Case Folding", "U+0069 'i' + U+0307 '\u{307}'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numeric() {
+        assert_eq!(numeric(0x41), None);
+        assert_eq!(
+            numeric(0x37),
+            Some(Row::field("Numeric Value", "7 (Decimal)"))
+        );
+        assert_eq!(
+            numeric(0xBE),
+            Some(Row::field("Numeric Value", "3/4 (Numeric)"))
+        );
+        assert_eq!(
+            numeric(0x4E07),
+            Some(Row::field("Numeric Value", "10000 (Numeric)"))
+        );
+    }
+
+    #[test]
+    fn test_bidi_fields() {
+        assert_eq!(
+            bidi_fields(0x5D0),
+            [Row::field("Direction", "Right to Left (R)")]
+        );
+        assert_eq!(
+            bidi_fields(0x28),
+            [
+                Row::field("Direction", "Other Neutral (ON)"),
+                Row::field("Mirrored", "U+0029 ')'"),
+                Row::field("Bracket", "Opening, paired with U+0029 ')'"),
+            ]
+        );
+        assert_eq!(
+            bidi_fields(0x221B),
+            [
+                Row::field("Direction", "Other Neutral (ON)"),
+                Row::field("Mirrored", "Yes (no mirrored character)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mirrored_glyph() {
+        assert_eq!(
+            mirrored_glyph(
+                0x41,
+                &DescribeOptions {
+                    draw: true,
+                    ..Default::default()
+                }
+            ),
+            [] as [String; 0]
+        );
+        assert_eq!(
+            mirrored_glyph(
+                0x28,
+                &DescribeOptions {
+                    draw: false,
+                    ..Default::default()
+                }
+            ),
+            [] as [String; 0]
+        );
+        let lines = mirrored_glyph(
+            0x28,
+            &DescribeOptions {
+                draw: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(lines[1], "Mirrored Glyph:");
+        assert_eq!(
+            lines[3..],
+            side_by_side(&glyph_lines(0x28), &glyph_lines(0x29), 0)
+        );
+    }
+
+    #[test]
+    fn test_properties() {
+        assert_eq!(properties(0x378), [] as [String; 0]);
+        let lines = properties(0x20);
+        assert_eq!(lines[1], "Properties:");
+        assert!(lines[3].starts_with(GLYPH_INDENT));
+        assert!(
+            lines[3..]
+                .join(" ")
+                .contains("Pattern White Space, White Space")
+        );
+        assert!(lines.iter().all(|line| line.len() <= 4 + PROPERTIES_WIDTH));
+    }
+
+    #[test]
+    fn test_break_fields() {
+        assert_eq!(
+            break_fields(0x41),
+            [
+                Row::field("Line Break", "Alphabetic (AL)"),
+                Row::field("Word Break", "ALetter (LE)"),
+                Row::field("Sentence Break", "Upper (UP)"),
+                Row::field("Grapheme Break", "Other (XX)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_cluster() {
+        assert_eq!(
+            describe_cluster(&[0x65, 0x301]),
+            [
+                "Grapheme Cluster 'e\u{301}'",
+                "2 codepoints",
+                "",
+                "    U+0065    'e'   LATIN SMALL LETTER E",
+                "    U+0301    '\u{301}'    COMBINING ACUTE ACCENT",
+            ]
+        );
+        assert_eq!(
+            describe_cluster(&[0x1F44D, 0x1F3FD]),
+            [
+                "Emoji Modifier Sequence '👍🏽'",
+                "thumbs up: medium skin tone",
+                "",
+                "    U+1F44D   '👍'  THUMBS UP SIGN",
+                "    U+1F3FD   '🏽'  EMOJI MODIFIER FITZPATRICK TYPE-4",
+            ]
+        );
+        let family = describe_cluster(&[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467]);
+        assert_eq!(family[1], "family: woman, woman, girl");
+        assert_eq!(family.len(), 8);
+        assert_eq!(describe_cluster(&[0x0D, 0x0A])[0], "Grapheme Cluster");
+    }
+
+    #[test]
+    fn test_components() {
+        let options = DescribeOptions {
+            draw: false,
+            ..Default::default()
+        };
+        assert!(components(0x41, &options).is_empty());
+        assert_eq!(
+            components(0xFB03, &options),
+            [
+                "",
+                "Components:",
+                "",
+                "    U+0066    'f'   LATIN SMALL LETTER F",
+                "    U+0066    'f'   LATIN SMALL LETTER F",
+                "    U+0069    'i'   LATIN SMALL LETTER I",
+            ]
+        );
+        let options = DescribeOptions {
+            draw: true,
+            ..Default::default()
+        };
+        let lines = components(0xC1, &options);
+        assert_eq!(lines.len(), 3 + 8 + 1 + 2);
+        assert_eq!(lines[12], "    U+0041    'A'   LATIN CAPITAL LETTER A");
+    }
+
+    #[test]
+    fn test_hangul_components() {
+        let options = DescribeOptions::default();
+        let lines = components(0xD55C, &options); // 한
+        assert_eq!(lines[1], "Jamo:");
+        assert_eq!(
+            lines[3..],
+            [
+                format!("    {} (H)", listing_line(0x1112)),
+                format!("    {} (A)", listing_line(0x1161)),
+                format!("    {} (N)", listing_line(0x11AB)),
+            ]
+        );
+        let lines = components(0xC544, &options); // 아
+        assert_eq!(lines[3], format!("    {} (silent)", listing_line(0x110B)));
+    }
+
+    #[test]
+    fn test_romanization() {
+        assert_eq!(romanization(0x41), None);
+        assert_eq!(
+            romanization(0xAC01),
+            Some(Row::field("Romanization", "gak".to_string()))
+        );
+    }
+
+    #[cfg(feature = "unihan")]
+    #[test]
+    fn test_unihan_fields() {
+        assert_eq!(unihan_fields(0x41), []);
+        let rows = unihan_fields(0x5186); // 円
+        assert!(rows.contains(&Row::field("Mandarin", "yuán".to_string())));
+        assert!(rows.contains(&Row::field("Total Strokes", "4".to_string())));
+        assert!(rows.contains(&Row::field("Radical-Stroke", "13.2 (⼌ + 2)".to_string())));
+    }
+
+    #[test]
+    fn test_block() {
+        assert_eq!(block(0xC1), "Latin-1 Supplement (U+0080–U+00FF)");
+        assert_eq!(block(0x2FE0), "No Block");
+    }
+
+    #[test]
+    fn test_plane() {
+        assert_eq!(plane(0xC1), "Basic Multilingual Plane (U+0000–U+FFFF)");
+        assert_eq!(plane(0x50000), "Plane 5 (U+50000–U+5FFFF)");
+    }
+
+    #[test]
+    fn test_extensions() {
+        assert_eq!(extensions(0x41), None);
+        let Some(Row::Field(label, value)) = extensions(0x0640) else {
+            panic!("ARABIC TATWEEL should have script extensions");
+        };
+        assert_eq!(label, "Script Extensions");
+        assert!(value.starts_with("Adlam (Adlm), Arabic (Arab), "));
+    }
+
+    #[test]
+    fn test_spacing() {
+        assert_eq!(spacing(0x41), "Yes (1 column)");
+        assert_eq!(spacing(0x5186), "Yes (2 columns)");
+        assert_eq!(spacing(0xC6), "Yes (1 column, 2 in East Asian contexts)");
+        assert_eq!(spacing(0x0301), "No (zero width)");
+    }
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(
+            encodings(0xC1),
+            vec![
+                Row::field("UTF-8", "C3 81"),
+                Row::field("UTF-16", "00 C1"),
+                Row::field("UTF-32", "00 00 00 C1"),
+            ]
+        );
+        assert_eq!(
+            encodings(0x1F44D),
+            vec![
+                Row::field("UTF-8", "F0 9F 91 8D"),
+                Row::field("UTF-16", "D8 3D DC 4D"),
+                Row::field("UTF-32", "00 01 F4 4D"),
+            ]
+        );
+        assert!(encodings(0xDC00).is_empty());
+    }
+
+    #[test]
+    fn test_describe_without_glyph() {
+        let options = DescribeOptions {
+            draw: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            describe(0x26, &options).join("\n"),
+            indoc! {"
+                U+0026 '&'
+                AMPERSAND
+
+                Unicode Version:  1.1 (June 1993)
+                          Block:  Basic Latin (U+0000–U+007F)
+                          Plane:  Basic Multilingual Plane (U+0000–U+FFFF)
+                         Script:  Common (Zyyy)
+                       Category:  Other Punctuation (Po)
+                        Spacing:  Yes (1 column)
+                      Direction:  Other Neutral (ON)
+                          UTF-8:  26
+                         UTF-16:  00 26
+                         UTF-32:  00 00 00 26
+                           HTML:  &amp;  &#38;  &#x26;"}
+        );
+    }
+
+    #[test]
+    fn test_describe_with_glyph() {
+        let options = DescribeOptions {
+            draw: true,
+            ..Default::default()
+        };
+        let lines = describe(0x61, &options);
+        assert_eq!(lines[0], format!("{}U+0061 'a'", " ".repeat(16)));
+        assert_eq!(lines[1], format!("{}LATIN SMALL LETTER A", " ".repeat(16)));
+        assert_eq!(
+            lines[4],
+            "     ▄▀▀▀▀▄               Block:  Basic Latin (U+0000–U+007F)"
+        );
+        assert_eq!(
+            lines[7],
+            "     ▀▄▄▄▀█            Category:  Lowercase Letter (Ll)"
+        );
+        let confusable = confusable_with(0x61, &options);
+        assert_eq!(lines.len(), 16 + confusable.len());
+    }
+
+    #[test]
+    fn test_confusable_with() {
+        let options = DescribeOptions::default();
+        assert!(confusable_with(0x5186, &options).is_empty());
+        let lines = confusable_with(0x430, &options);
+        assert_eq!(lines[..3], ["", "Confusable with:", ""]);
+        assert!(lines.contains(&format!("    {}", listing_line(0x61))));
+        let options = DescribeOptions {
+            draw: true,
+            ..Default::default()
+        };
+        let lines = confusable_with(0x430, &options);
+        // The glyph of the character comes first, followed by those of its confusables.
+        let pair = side_by_side(&glyph_lines(0x430), &glyph_lines(0x61), 0);
+        for (line, expected) in lines[3..].iter().zip(&pair) {
+            assert!(line.starts_with(expected.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_glyph_rows() {
+        let height = glyph(0x41).len();
+        assert_eq!(glyph_rows(&[0x41, 0x42]).len(), height);
+        let lines = glyph_rows(&[0x41; 12]);
+        assert_eq!(lines.len(), 2 * height + 1);
+        assert!(lines.iter().all(|line| text_width(line) <= GLYPH_ROW_WIDTH));
+    }
+}
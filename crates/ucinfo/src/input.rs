@@ -0,0 +1,319 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Reading of characters from stdin and files, given as `-` and with `--file`.
+
+use std::collections::HashMap;
+use std::io::{self, Read};
+
+use crate::layout::pad_left;
+use crate::listing::listing_line;
+
+/// Size of the blocks in which the input is read.
+const BLOCK_SIZE: usize = 8192;
+
+/// Something found in the input: a grapheme cluster, or a sequence of bytes that is not valid
+/// UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Chunk {
+    Cluster(Vec<u32>),
+    Invalid {
+        /// Offset of the sequence in the input, in bytes.
+        offset: usize,
+        bytes: Vec<u8>,
+    },
+}
+
+/// Split valid text into grapheme clusters.
+fn clusters(text: &str) -> impl Iterator<Item = Chunk> + '_ {
+    unicodedata::graphemes(text)
+        .map(|cluster| Chunk::Cluster(cluster.chars().map(u32::from).collect()))
+}
+
+/// Split bytes into grapheme clusters and invalid UTF-8 sequences, where `offset` is the offset
+/// of the bytes in the input, and return how many of them were handled.
+///
+/// Unless `at_end` is set, the last grapheme cluster and an incomplete sequence after it are left
+/// unhandled, since they may continue in the bytes that follow. An error from `handle` stops the
+/// decoding.
+fn decode(
+    bytes: &[u8],
+    offset: usize,
+    at_end: bool,
+    handle: &mut impl FnMut(Chunk) -> io::Result<()>,
+) -> io::Result<usize> {
+    let mut done = 0;
+    loop {
+        let rest = &bytes[done..];
+        let (valid, error_len) = match std::str::from_utf8(rest) {
+            Ok(_) => (rest.len(), None),
+            Err(error) => (error.valid_up_to(), error.error_len()),
+        };
+        // The bytes before the error are valid.
+        let text = std::str::from_utf8(&rest[..valid]).unwrap_or_default();
+        if let Some(len) = error_len {
+            clusters(text).try_for_each(&mut *handle)?;
+            handle(Chunk::Invalid {
+                offset: offset + done + valid,
+                bytes: rest[valid..valid + len].to_vec(),
+            })?;
+            done += valid + len;
+        } else if at_end {
+            clusters(text).try_for_each(&mut *handle)?;
+            if valid < rest.len() {
+                handle(Chunk::Invalid {
+                    offset: offset + done + valid,
+                    bytes: rest[valid..].to_vec(),
+                })?;
+            }
+            return Ok(bytes.len());
+        } else {
+            let mut clusters: Vec<&str> = unicodedata::graphemes(text).collect();
+            // Nothing can be added to a cluster that ends a line.
+            let held = match clusters.last() {
+                Some(last) if !last.ends_with('\n') || valid < rest.len() => clusters.pop(),
+                _ => None,
+            };
+            for cluster in clusters {
+                handle(Chunk::Cluster(cluster.chars().map(u32::from).collect()))?;
+            }
+            return Ok(done + valid - held.map_or(0, str::len));
+        }
+    }
+}
+
+/// Read UTF-8 text in blocks of `block_size` bytes, passing each grapheme cluster and invalid
+/// sequence to `handle` as soon as it is complete.
+fn read_blocks<R: Read>(
+    mut reader: R,
+    block_size: usize,
+    mut handle: impl FnMut(Chunk) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut block = vec![0; block_size];
+    // Bytes read but not handled yet, and their offset in the input.
+    let mut pending = Vec::new();
+    let mut offset = 0;
+    loop {
+        let len = match reader.read(&mut block) {
+            Ok(len) => len,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        pending.extend_from_slice(&block[..len]);
+        let done = decode(&pending, offset, len == 0, &mut handle)?;
+        pending.drain(..done);
+        offset += done;
+        if len == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Read UTF-8 text, passing each grapheme cluster and invalid sequence to `handle` as soon as it
+/// has been read. The input is read in fixed-size blocks, so that long inputs are streamed rather
+/// than read at once, even if they have no line breaks. Reading stops at the first error returned
+/// by `handle`, such as a failure to write the output.
+pub(crate) fn read_chunks<R: Read>(
+    reader: R,
+    handle: impl FnMut(Chunk) -> io::Result<()>,
+) -> io::Result<()> {
+    read_blocks(reader, BLOCK_SIZE, handle)
+}
+
+/// Describe an invalid UTF-8 sequence, e.g. `Invalid UTF-8 in <stdin> at byte 12: FF`.
+pub(crate) fn format_invalid(source: &str, offset: usize, bytes: &[u8]) -> String {
+    let bytes: Vec<String> = bytes.iter().map(|byte| format!("{byte:02X}")).collect();
+    format!(
+        "Invalid UTF-8 in {source} at byte {offset}: {}",
+        bytes.join(" ")
+    )
+}
+
+/// Number of times each codepoint occurs, in order of first appearance, for `--unique`.
+#[derive(Debug, Default)]
+pub(crate) struct Counts {
+    counts: Vec<(u32, usize)>,
+    index: HashMap<u32, usize>,
+}
+
+impl Counts {
+    /// Count an occurrence of a codepoint.
+    pub(crate) fn add(&mut self, codepoint: u32) {
+        let idx = *self.index.entry(codepoint).or_insert_with(|| {
+            self.counts.push((codepoint, 0));
+            self.counts.len() - 1
+        });
+        self.counts[idx].1 += 1;
+    }
+
+    /// List the codepoints with their counts, most frequent first, then in order of first
+    /// appearance.
+    pub(crate) fn listing(&self) -> Vec<String> {
+        let mut counts = self.counts.clone();
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        let width = counts
+            .first()
+            .map_or(0, |&(_, count)| count.to_string().len());
+        counts
+            .iter()
+            .map(|&(cp, count)| {
+                format!(
+                    "{}  {}",
+                    pad_left(&count.to_string(), width),
+                    listing_line(cp)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(bytes: &[u8]) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        read_chunks(bytes, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        })
+        .unwrap();
+        chunks
+    }
+
+    #[test]
+    fn test_read_chunks() {
+        assert_eq!(
+            read("a\r\ne\u{301}".as_bytes()),
+            [
+                Chunk::Cluster(vec![0x61]),
+                Chunk::Cluster(vec![0x0D, 0x0A]),
+                Chunk::Cluster(vec![0x65, 0x301]),
+            ]
+        );
+        assert!(read(b"").is_empty());
+    }
+
+    #[test]
+    fn test_block_boundaries() {
+        // Splitting the input into tiny blocks cuts through clusters and multibyte sequences,
+        // which must not change what is found.
+        let inputs: [&[u8]; 4] = [
+            "a\r\ne\u{301}\u{302}円👍🏽\n".as_bytes(),
+            b"a\n\xFFb\xE2\x82",
+            b"\xE2\x82\xF0\x9F\x91\x8D\xC3",
+            "🇧🇷🇵🇹".as_bytes(),
+        ];
+        for input in inputs {
+            let expected = read(input);
+            for block_size in 1..=4 {
+                let mut chunks = Vec::new();
+                read_blocks(input, block_size, |chunk| {
+                    chunks.push(chunk);
+                    Ok(())
+                })
+                .unwrap();
+                assert_eq!(chunks, expected, "block size {block_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_without_newlines() {
+        /// A reader of a long line, which records how many bytes were read.
+        struct Line {
+            len: usize,
+            read: std::rc::Rc<std::cell::Cell<usize>>,
+        }
+        impl Read for Line {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let len = buf.len().min(self.len - self.read.get());
+                buf[..len].fill(b'x');
+                self.read.set(self.read.get() + len);
+                Ok(len)
+            }
+        }
+
+        let read = std::rc::Rc::new(std::cell::Cell::new(0));
+        let line = Line {
+            len: BLOCK_SIZE * 16,
+            read: read.clone(),
+        };
+        let mut read_before_first = None;
+        let mut count = 0;
+        read_chunks(line, |_| {
+            read_before_first.get_or_insert(read.get());
+            count += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(read_before_first, Some(BLOCK_SIZE));
+        assert_eq!(count, BLOCK_SIZE * 16);
+
+        // An error from the handler, e.g. a closed pipe, stops the reading.
+        read.set(0);
+        let line = Line {
+            len: BLOCK_SIZE * 16,
+            read: read.clone(),
+        };
+        let error = read_chunks(line, |_| Err(io::ErrorKind::BrokenPipe.into())).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(read.get(), BLOCK_SIZE);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        assert_eq!(
+            read(b"a\n\xFFb\xE2\x82"),
+            [
+                Chunk::Cluster(vec![0x61]),
+                Chunk::Cluster(vec![0x0A]),
+                Chunk::Invalid {
+                    offset: 2,
+                    bytes: vec![0xFF]
+                },
+                Chunk::Cluster(vec![0x62]),
+                Chunk::Invalid {
+                    offset: 4,
+                    bytes: vec![0xE2, 0x82]
+                },
+            ]
+        );
+        assert_eq!(
+            format_invalid("<stdin>", 4, &[0xE2, 0x82]),
+            "Invalid UTF-8 in <stdin> at byte 4: E2 82"
+        );
+    }
+
+    #[test]
+    fn test_counts() {
+        let mut counts = Counts::default();
+        for ch in "banana".chars() {
+            counts.add(u32::from(ch));
+        }
+        assert_eq!(
+            counts.listing(),
+            [
+                format!("3  {}", listing_line(0x61)),
+                format!("2  {}", listing_line(0x6E)),
+                format!("1  {}", listing_line(0x62)),
+            ]
+        );
+        assert!(Counts::default().listing().is_empty());
+    }
+}
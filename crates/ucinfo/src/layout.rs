@@ -0,0 +1,206 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Layout of the information shown for each character.
+//!
+//! Everything here measures text by its display width in a terminal, rather than by its length,
+//! so that columns stay aligned even when the text contains wide characters.
+
+use unicodedata::{AmbiguousWidth, str_width};
+
+/// A row in a column of information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Row {
+    /// An empty row.
+    Blank,
+    /// Free text, such as a heading.
+    Text(String),
+    /// A labeled value. Labels of all fields in a column are right-aligned to each other.
+    Field(String, String),
+}
+
+impl Row {
+    /// Create a labeled field.
+    pub(crate) fn field(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Field(label.into(), value.into())
+    }
+}
+
+/// Separator between the label and the value of a field.
+const FIELD_SEPARATOR: &str = ":  ";
+
+/// Display width of a string in a terminal, in columns.
+///
+/// Characters of ambiguous width are taken as narrow, as most terminals do.
+pub(crate) fn text_width(text: &str) -> usize {
+    str_width(text, AmbiguousWidth::Narrow)
+}
+
+/// Pad a string with spaces on the right, up to the given display width.
+pub(crate) fn pad_right(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text_width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
+/// Pad a string with spaces on the left, up to the given display width.
+pub(crate) fn pad_left(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text_width(text));
+    format!("{}{text}", " ".repeat(padding))
+}
+
+/// Format rows into lines, right-aligning the labels of all fields.
+pub(crate) fn format_rows(rows: &[Row]) -> Vec<String> {
+    let label_width = rows
+        .iter()
+        .filter_map(|row| match row {
+            Row::Field(label, _) => Some(text_width(label)),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    rows.iter()
+        .map(|row| match row {
+            Row::Blank => String::new(),
+            Row::Text(text) => text.clone(),
+            Row::Field(label, value) => {
+                format!("{}{FIELD_SEPARATOR}{value}", pad_left(label, label_width))
+            }
+        })
+        .collect()
+}
+
+/// Put two columns of lines side by side, separated by a gap of the given width.
+///
+/// The left column is padded to the width of its widest line, and the shorter column is extended
+/// with empty lines. Trailing whitespace is removed from every line.
+pub(crate) fn side_by_side<L, R>(left: &[L], right: &[R], gap: usize) -> Vec<String>
+where
+    L: AsRef<str>,
+    R: AsRef<str>,
+{
+    let left_width = left
+        .iter()
+        .map(|line| text_width(line.as_ref()))
+        .max()
+        .unwrap_or(0);
+    (0..left.len().max(right.len()))
+        .map(|idx| {
+            let left = left.get(idx).map(AsRef::as_ref).unwrap_or("");
+            let right = right.get(idx).map(AsRef::as_ref).unwrap_or("");
+            let line = format!("{}{}{right}", pad_right(left, left_width), " ".repeat(gap));
+            line.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Join items with commas, breaking the list into lines no wider than the given width (unless a
+/// single item is wider).
+pub(crate) fn wrap_list<T: AsRef<str>>(items: &[T], width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for (idx, item) in items.iter().enumerate() {
+        let item = item.as_ref();
+        let item = if idx + 1 < items.len() {
+            format!("{item},")
+        } else {
+            item.to_string()
+        };
+        if !line.is_empty() && text_width(&line) + 1 + text_width(&item) > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_text_width() {
+        assert_eq!(text_width("abc"), 3);
+        assert_eq!(text_width("円"), 2);
+        assert_eq!(text_width("A\u{301}"), 1);
+        assert_eq!(text_width("▄▀█"), 3);
+    }
+
+    #[test]
+    fn test_padding() {
+        assert_eq!(pad_right("円", 4), "円  ");
+        assert_eq!(pad_left("円", 4), "  円");
+        assert_eq!(pad_left("long", 2), "long");
+    }
+
+    #[test]
+    fn test_format_rows() {
+        let rows = [
+            Row::Text("U+5186 '円'".into()),
+            Row::Blank,
+            Row::field("Block", "CJK Unified Ideographs"),
+            Row::field("Unicode Version", "1.1"),
+        ];
+        assert_eq!(
+            format_rows(&rows).join("\n"),
+            indoc! {"
+                U+5186 '円'
+
+                          Block:  CJK Unified Ideographs
+                Unicode Version:  1.1"}
+        );
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        let left = ["▄▄", "円円", "█"];
+        let right = ["one", "two"];
+        assert_eq!(
+            side_by_side(&left, &right, 2).join("\n"),
+            "▄▄    one\n円円  two\n█"
+        );
+        // Wide characters in the right column don’t affect the left one.
+        let right = ["円円円", "x"];
+        assert_eq!(
+            side_by_side(&left, &right, 1).join("\n"),
+            "▄▄   円円円\n円円 x\n█"
+        );
+    }
+
+    #[test]
+    fn test_wrap_list() {
+        let items = ["Alphabetic", "Cased", "ID Start", "Uppercase"];
+        assert_eq!(
+            wrap_list(&items, 20),
+            ["Alphabetic, Cased,", "ID Start, Uppercase"]
+        );
+        assert_eq!(
+            wrap_list(&items, 80),
+            ["Alphabetic, Cased, ID Start, Uppercase"]
+        );
+        assert_eq!(wrap_list(&["Default Ignorable Code Point"], 10).len(), 1);
+        assert_eq!(wrap_list::<&str>(&[], 80), [] as [String; 0]);
+    }
+}
@@ -0,0 +1,101 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Compact listings of characters, one per line, used when showing many characters at once.
+
+use unicodedata::{Block, Script, characters_in_script, display_name, is_assigned};
+
+use crate::describe::{format_codepoint, format_range, printable};
+use crate::layout::pad_right;
+
+/// Width of the codepoint column, enough for `U+10FFFF` and a gap.
+const CODEPOINT_WIDTH: usize = 10;
+
+/// Width of the character column, enough for a quoted wide character and a gap.
+const CHARACTER_WIDTH: usize = 6;
+
+/// Format a listing line for a codepoint, with the codepoint, the character and its name.
+pub(crate) fn listing_line(codepoint: u32) -> String {
+    let character = printable(codepoint).unwrap_or_default();
+    format!(
+        "{}{}{}",
+        pad_right(&format_codepoint(codepoint), CODEPOINT_WIDTH),
+        pad_right(&character, CHARACTER_WIDTH),
+        display_name(codepoint)
+    )
+}
+
+/// List codepoints, one per line.
+pub(crate) fn list(codepoints: impl IntoIterator<Item = u32>) -> Vec<String> {
+    codepoints.into_iter().map(listing_line).collect()
+}
+
+/// List the assigned characters of a block, under a heading with its name and range.
+pub(crate) fn list_block(block: Block) -> Vec<String> {
+    let heading = format!("{} ({})", block.name, format_range(block.first, block.last));
+    let mut lines = vec![heading, String::new()];
+    lines.extend(list(block.codepoints().filter(|&cp| is_assigned(cp))));
+    lines
+}
+
+/// List the characters of a script, under a heading with its name and code.
+pub(crate) fn list_script(script: Script) -> Vec<String> {
+    let mut lines = vec![script.to_string(), String::new()];
+    lines.extend(list(characters_in_script(script)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listing_line() {
+        assert_eq!(listing_line(0x41), "U+0041    'A'   LATIN CAPITAL LETTER A");
+        assert_eq!(
+            listing_line(0x5186),
+            "U+5186    '円'  CJK UNIFIED IDEOGRAPH-5186"
+        );
+        assert_eq!(listing_line(0x0A), "U+000A          <control-000A>");
+        assert_eq!(listing_line(0x1F44D), "U+1F44D   '👍'  THUMBS UP SIGN");
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list(0x30..=0x39).len(), 10);
+        assert!(list([]).is_empty());
+    }
+
+    #[test]
+    fn test_list_block() {
+        let lines = list_block(unicodedata::block_by_name("Basic Latin").unwrap());
+        assert_eq!(lines.len(), 2 + 128);
+        assert_eq!(lines[0], "Basic Latin (U+0000–U+007F)");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2], "U+0000          <control-0000>");
+        assert_eq!(lines[67], "U+0041    'A'   LATIN CAPITAL LETTER A");
+    }
+
+    #[test]
+    fn test_list_script() {
+        let lines = list_script(Script::by_name("Georgian").unwrap());
+        assert_eq!(lines[0], "Georgian (Geor)");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2], "U+10A0    'Ⴀ'   GEORGIAN CAPITAL LETTER AN");
+    }
+}
@@ -0,0 +1,513 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+mod audit;
+mod describe;
+mod input;
+mod layout;
+mod listing;
+mod normalization;
+#[cfg(feature = "unihan")]
+mod radicals;
+mod scan;
+mod search;
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read, Write};
+
+use anyhow::{Context, Result, bail};
+use getopts::{Matches, Options};
+
+use crate::audit::audit;
+use crate::describe::{DescribeOptions, describe, describe_cluster};
+use crate::input::{Chunk, Counts, format_invalid, read_chunks};
+use crate::listing::{list, list_block, list_script};
+use crate::normalization::describe_normalization;
+use crate::scan::scan_files;
+use crate::search::{Filter, search};
+
+/// Brief description of the program, shown before the options in the help message.
+const BRIEF: &str = "\
+Show information about Unicode characters.
+
+ucinfo [OPTIONS] [CHARACTER...] [--stdin] [--file FILE...] [--unique]
+
+ucinfo [OPTIONS] -N [STRING...]
+
+ucinfo [OPTIONS] -S SEARCH [--category GC] [--added-in VERSION] [--value NUMBER]
+                            [--property PROPERTY...]
+
+ucinfo [OPTIONS] -b BLOCK
+
+ucinfo [OPTIONS] --script SCRIPT
+
+ucinfo [OPTIONS] --radical RADICAL [--strokes STROKES]
+
+ucinfo [OPTIONS] --audit STRING
+
+ucinfo [OPTIONS] --scan FILE...
+
+CHARACTER can be either a string, which will show information for each
+character in the string, grouped into grapheme clusters (user-perceived
+characters), or a Unicode code point in the form U+XXXX. An argument of
+\"-\", or --stdin, reads the characters from stdin, and --file reads them
+from FILE; either is described as it is read, and invalid UTF-8 is
+reported with its byte offset. --unique lists each different character
+once instead, with the number of times it occurs.
+
+SEARCH is a list of words that must all appear in the names of the
+characters found. GC is a general category, such as \"Sm\" or
+\"Math Symbol\", or a major class, such as \"S\" or \"Symbol\". VERSION
+is a version of Unicode, such as \"15.1\". NUMBER is a numeric value,
+such as \"5\", \"1/4\" or \"0.25\". PROPERTY is a binary property, such
+as \"White_Space\" or \"WSpace\", and can be given more than once. Filters
+can also be used without a search, to list all the characters that meet
+them.
+
+BLOCK is the name of a Unicode block, such as \"Latin-1 Supplement\";
+case, spaces, hyphens and underscores are ignored. SCRIPT is the name or
+ISO 15924 code of a script, such as \"Georgian\" or \"Geor\".
+
+RADICAL is the number of a KangXi radical, such as \"13\", or the radical
+itself, such as \"冂\". STROKES is the number of strokes besides the
+radical. Listing ideographs by radical requires ucinfo to be built with
+the \"unihan\" feature.
+
+--audit shows the scripts mixed in STRING, such as a user name, its
+restriction level, and the characters in it that are restricted in
+identifiers or that can be used to hide or disguise text (UTS #39).
+
+--scan reports each bidi control, zero width, invisible, tag, variation
+selector and homoglyph character in each FILE, with its line and column,
+as a check against Trojan Source attacks. It exits with status 1 if any
+are found, or with status 2 if a FILE cannot be read.";
+
+/// Highest valid Unicode codepoint.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+/// Parse a command-line argument into the codepoints it stands for, grouped into grapheme
+/// clusters.
+///
+/// An argument in the form `U+XXXX` stands for that codepoint, anything else for each of the
+/// characters in it.
+fn parse_argument(arg: &str) -> Result<Vec<Vec<u32>>> {
+    let hex = arg.strip_prefix("U+").or_else(|| arg.strip_prefix("u+"));
+    match hex {
+        Some(hex) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+            match u32::from_str_radix(hex, 16) {
+                Ok(codepoint) if codepoint <= MAX_CODEPOINT => Ok(vec![vec![codepoint]]),
+                _ => bail!("Invalid codepoint: {arg}"),
+            }
+        }
+        _ => Ok(unicodedata::graphemes(arg)
+            .map(|cluster| cluster.chars().map(u32::from).collect())
+            .collect()),
+    }
+}
+
+/// Options that can be given along with the characters to describe. Their positions tell where
+/// the free arguments were on the command line.
+const DESCRIBE_OPTIONS: &[&str] = &["stdin", "file", "unique", "no-draw", "all"];
+
+/// A source of characters to describe.
+#[derive(Debug, PartialEq, Eq)]
+enum Input {
+    /// The grapheme clusters of a command-line argument.
+    Argument(Vec<Vec<u32>>),
+    /// The standard input, given as `-` or with `--stdin`.
+    Stdin,
+    /// A file given with `--file`.
+    File(String),
+}
+
+/// Collect the inputs given on the command line, in the order they were given. Arguments are
+/// parsed here, so that they are all checked before anything is shown.
+fn inputs(matches: &Matches) -> Result<Vec<Input>> {
+    let taken: BTreeSet<usize> = DESCRIBE_OPTIONS
+        .iter()
+        .flat_map(|&name| matches.opt_positions(name))
+        .collect();
+    // getopts counts free arguments and options alike, so the free arguments are at the
+    // positions that no option took.
+    let mut free_positions = (0..).filter(|pos| !taken.contains(pos));
+
+    let mut inputs = Vec::new();
+    for arg in &matches.free {
+        let input = match arg.as_str() {
+            "-" => Input::Stdin,
+            _ => Input::Argument(parse_argument(arg)?),
+        };
+        inputs.push((free_positions.next().unwrap_or_default(), input));
+    }
+    for pos in matches.opt_positions("stdin") {
+        inputs.push((pos, Input::Stdin));
+    }
+    for (pos, path) in matches.opt_strs_pos("file") {
+        inputs.push((pos, Input::File(path)));
+    }
+    // The sort is stable, and no two inputs share a position.
+    inputs.sort_by_key(|&(pos, _)| pos);
+    Ok(inputs.into_iter().map(|(_, input)| input).collect())
+}
+
+/// Write lines to the output.
+fn write_lines(out: &mut impl Write, lines: impl IntoIterator<Item = String>) -> io::Result<()> {
+    lines
+        .into_iter()
+        .try_for_each(|line| writeln!(out, "{line}"))
+}
+
+/// Write the description of a grapheme cluster: the cluster as a whole, if it has more than one
+/// codepoint, and then each of its codepoints.
+fn print_cluster(
+    out: &mut impl Write,
+    cluster: &[u32],
+    options: &DescribeOptions,
+) -> io::Result<()> {
+    if cluster.len() > 1 {
+        writeln!(out)?;
+        write_lines(out, describe_cluster(cluster))?;
+    }
+    for &codepoint in cluster {
+        writeln!(out)?;
+        write_lines(out, describe(codepoint, options))?;
+    }
+    Ok(())
+}
+
+/// Read characters from stdin or a file, passing each grapheme cluster to `show` as it is read,
+/// and reporting invalid UTF-8 on stderr.
+fn read_input(
+    reader: impl Read,
+    source: &str,
+    show: &mut impl FnMut(Vec<u32>) -> io::Result<()>,
+) -> Result<()> {
+    read_chunks(reader, |chunk| match chunk {
+        Chunk::Cluster(cluster) => show(cluster),
+        Chunk::Invalid { offset, bytes } => {
+            eprintln!("{}", format_invalid(source, offset, &bytes));
+            Ok(())
+        }
+    })
+    .with_context(|| format!("Cannot read {source}"))
+}
+
+/// Whether an error comes from writing to a closed pipe, e.g. when the output is piped to `head`.
+fn is_broken_pipe(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<io::Error>()
+            .is_some_and(|error| error.kind() == io::ErrorKind::BrokenPipe)
+    })
+}
+
+fn options() -> Options {
+    let mut opts = Options::new();
+    opts.optopt(
+        "S",
+        "search",
+        "Search for characters by Unicode name",
+        "STRING",
+    );
+    opts.optopt(
+        "",
+        "category",
+        "Only find characters in a general category",
+        "GC",
+    );
+    opts.optopt(
+        "",
+        "added-in",
+        "Only find characters added in a version of Unicode",
+        "VERSION",
+    );
+    opts.optopt(
+        "",
+        "value",
+        "Only find characters with a numeric value",
+        "NUMBER",
+    );
+    opts.optmulti(
+        "",
+        "property",
+        "Only find characters with a binary property",
+        "PROPERTY",
+    );
+    opts.optopt("b", "block", "List the characters in a block", "BLOCK");
+    opts.optopt("", "script", "List the characters in a script", "SCRIPT");
+    opts.optopt(
+        "",
+        "radical",
+        "List the CJK ideographs with a radical",
+        "RADICAL",
+    );
+    opts.optopt(
+        "",
+        "strokes",
+        "Only list ideographs with a number of residual strokes",
+        "STROKES",
+    );
+    opts.optopt(
+        "",
+        "audit",
+        "Check a string for mixed scripts and spoofing characters",
+        "STRING",
+    );
+    opts.optflag(
+        "",
+        "scan",
+        "Report characters that can hide or disguise code in files",
+    );
+    opts.optflag(
+        "N",
+        "normalize",
+        "Show the normalization forms of each argument",
+    );
+    opts.optflag("", "stdin", "Describe the characters read from stdin");
+    opts.optmulti("", "file", "Describe the characters in a file", "FILE");
+    opts.optflag(
+        "",
+        "unique",
+        "List each different character once, with its count",
+    );
+    opts.optflag("n", "no-draw", "Do not draw character glyphs");
+    opts.optflag("a", "all", "Show all available information");
+    opts.optflag("h", "help", "Show this help message and exit");
+    opts.optflag("V", "version", "Show version information and exit");
+    opts
+}
+
+fn main() -> Result<()> {
+    let mut out = io::stdout().lock();
+    match run(&mut out) {
+        // The reader of the output went away, so there is no one left to tell.
+        Err(error) if is_broken_pipe(&error) => Ok(()),
+        result => result,
+    }
+}
+
+/// Run the program, writing its output to `out`.
+fn run(out: &mut impl Write) -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let opts = options();
+    let matches = opts.parse(&args)?;
+
+    if matches.opt_present("help") {
+        write!(out, "{}", opts.usage(BRIEF))?;
+        return Ok(());
+    }
+    if matches.opt_present("version") {
+        writeln!(
+            out,
+            "ucinfo {} (Unifont {})",
+            env!("CARGO_PKG_VERSION"),
+            unifont::UNIFONT_VERSION
+        )?;
+        return Ok(());
+    }
+
+    if ["search", "category", "added-in", "value", "property"]
+        .iter()
+        .any(|&opt| matches.opt_present(opt))
+    {
+        let query = matches.opt_str("search").unwrap_or_default();
+        let mut filters = Vec::new();
+        if let Some(category) = matches.opt_str("category") {
+            filters.push(Filter::category(&category)?);
+        }
+        if let Some(version) = matches.opt_str("added-in") {
+            filters.push(Filter::added_in(&version)?);
+        }
+        if let Some(value) = matches.opt_str("value") {
+            filters.push(Filter::value(&value)?);
+        }
+        for property in matches.opt_strs("property") {
+            filters.push(Filter::property(&property)?);
+        }
+        write_lines(out, list(search(&query, &filters)))?;
+        return Ok(());
+    }
+
+    if let Some(name) = matches.opt_str("block") {
+        let Some(block) = unicodedata::block_by_name(&name) else {
+            bail!("Unknown block: {name}");
+        };
+        write_lines(out, list_block(block))?;
+        return Ok(());
+    }
+
+    if let Some(name) = matches.opt_str("script") {
+        let Some(script) = unicodedata::Script::by_name(&name) else {
+            bail!("Unknown script: {name}");
+        };
+        write_lines(out, list_script(script))?;
+        return Ok(());
+    }
+
+    if matches.opt_present("strokes") && !matches.opt_present("radical") {
+        bail!("--strokes can only be used with --radical");
+    }
+    if let Some(radical) = matches.opt_str("radical") {
+        #[cfg(feature = "unihan")]
+        {
+            let radical = radicals::parse_radical(&radical)?;
+            let strokes = matches
+                .opt_str("strokes")
+                .map(|strokes| radicals::parse_strokes(&strokes))
+                .transpose()?;
+            let draw = !matches.opt_present("no-draw");
+            write_lines(out, radicals::list_radical(radical, strokes, draw))?;
+            return Ok(());
+        }
+        #[cfg(not(feature = "unihan"))]
+        bail!("Cannot list ideographs with radical {radical}: built without the unihan feature");
+    }
+
+    if let Some(text) = matches.opt_str("audit") {
+        write_lines(out, audit(&text))?;
+        return Ok(());
+    }
+
+    if matches.opt_present("scan") {
+        if matches.free.is_empty() {
+            bail!("No files to scan");
+        }
+        let report = scan_files(&matches.free);
+        write_lines(out, report.findings.iter().cloned())?;
+        for error in &report.errors {
+            eprintln!("{error}");
+        }
+        if !report.findings.is_empty() {
+            let count = report.findings.len();
+            let plural = if count == 1 { "" } else { "s" };
+            eprintln!("{count} suspicious character{plural} found");
+        }
+        match report.status() {
+            0 => return Ok(()),
+            status => {
+                out.flush()?;
+                std::process::exit(status)
+            }
+        }
+    }
+
+    if matches.opt_present("normalize") {
+        for arg in &matches.free {
+            writeln!(out)?;
+            write_lines(out, describe_normalization(arg))?;
+        }
+        return Ok(());
+    }
+
+    let options = DescribeOptions {
+        draw: !matches.opt_present("no-draw"),
+        all: matches.opt_present("all"),
+    };
+
+    let inputs = inputs(&matches)?;
+    let unique = matches.opt_present("unique");
+    let mut counts = Counts::default();
+    let mut show = |cluster: Vec<u32>| {
+        if unique {
+            cluster.iter().for_each(|&cp| counts.add(cp));
+            Ok(())
+        } else {
+            print_cluster(out, &cluster, &options)
+        }
+    };
+    for input in inputs {
+        match input {
+            Input::Argument(clusters) => clusters.into_iter().try_for_each(&mut show)?,
+            Input::Stdin => read_input(io::stdin().lock(), "<stdin>", &mut show)?,
+            Input::File(path) => {
+                let file = File::open(&path).with_context(|| format!("Cannot read {path}"))?;
+                read_input(file, &path, &mut show)?;
+            }
+        }
+    }
+
+    if unique {
+        write_lines(out, counts.listing())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_argument() {
+        assert_eq!(parse_argument("U+0041").unwrap(), [[0x41]]);
+        assert_eq!(parse_argument("u+1f44d").unwrap(), [[0x1F44D]]);
+        assert_eq!(parse_argument("U+D800").unwrap(), [[0xD800]]);
+        assert_eq!(parse_argument("Á円").unwrap(), [[0xC1], [0x5186]]);
+        assert_eq!(parse_argument("U+").unwrap(), [[0x55], [0x2B]]);
+        assert_eq!(
+            parse_argument("U+XYZ").unwrap(),
+            [[0x55], [0x2B], [0x58], [0x59], [0x5A]]
+        );
+        assert_eq!(
+            parse_argument("e\u{301}👍🏽").unwrap(),
+            [vec![0x65, 0x301], vec![0x1F44D, 0x1F3FD]]
+        );
+        assert!(parse_argument("U+110000").is_err());
+    }
+
+    #[test]
+    fn test_inputs() {
+        let parse = |args: &[&str]| inputs(&options().parse(args).unwrap()).unwrap();
+        assert_eq!(
+            parse(&["a", "--file", "x.txt", "-n", "-", "--file=y.txt", "U+42"]),
+            [
+                Input::Argument(vec![vec![0x61]]),
+                Input::File("x.txt".into()),
+                Input::Stdin,
+                Input::File("y.txt".into()),
+                Input::Argument(vec![vec![0x42]]),
+            ]
+        );
+        assert_eq!(
+            parse(&["a", "--stdin", "b"]),
+            [
+                Input::Argument(vec![vec![0x61]]),
+                Input::Stdin,
+                Input::Argument(vec![vec![0x62]]),
+            ]
+        );
+        assert_eq!(
+            parse(&["--file", "x.txt", "-na", "--unique", "b", "--", "--file"]),
+            [
+                Input::File("x.txt".into()),
+                Input::Argument(vec![vec![0x62]]),
+                Input::Argument(vec![
+                    vec![0x2D],
+                    vec![0x2D],
+                    vec![0x66],
+                    vec![0x69],
+                    vec![0x6C],
+                    vec![0x65]
+                ]),
+            ]
+        );
+        assert!(inputs(&options().parse(["U+110000"]).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Normalization forms of a string, shown with `--normalize`.
+
+use unicodedata::{NormalizationForm, is_normalized, normalize};
+
+use crate::describe::format_codepoint;
+use crate::layout::{Row, format_rows};
+
+/// Format the codepoints of a string as space-separated `U+XXXX`.
+fn format_codepoints(text: &str) -> String {
+    text.chars()
+        .map(|ch| format_codepoint(u32::from(ch)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Describe the normalization of a string: its codepoints, each of its normalization forms, and
+/// the forms it is already in.
+pub(crate) fn describe_normalization(text: &str) -> Vec<String> {
+    let mut rows = vec![
+        Row::Text(format!("'{text}'")),
+        Row::Blank,
+        Row::field("Input", format_codepoints(text)),
+    ];
+    for form in NormalizationForm::ALL {
+        let normalized = normalize(text, form);
+        rows.push(Row::field(
+            form.name(),
+            format!("'{normalized}'  {}", format_codepoints(&normalized)),
+        ));
+    }
+    let forms: Vec<&str> = NormalizationForm::ALL
+        .into_iter()
+        .filter(|&form| is_normalized(text, form))
+        .map(NormalizationForm::name)
+        .collect();
+    let normalized = if forms.is_empty() {
+        "No".to_string()
+    } else {
+        format!("Yes ({})", forms.join(", "))
+    };
+    rows.push(Row::field("Normalized", normalized));
+    format_rows(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_describe_normalization() {
+        assert_eq!(
+            describe_normalization("Åﬁ").join("\n"),
+            indoc! {"
+                'Åﬁ'
+
+                     Input:  U+00C5 U+FB01
+                       NFC:  'Åﬁ'  U+00C5 U+FB01
+                       NFD:  'A\u{30A}ﬁ'  U+0041 U+030A U+FB01
+                      NFKC:  'Åfi'  U+00C5 U+0066 U+0069
+                      NFKD:  'A\u{30A}fi'  U+0041 U+030A U+0066 U+0069
+                Normalized:  Yes (NFC)"}
+        );
+        let lines = describe_normalization("abc");
+        assert_eq!(
+            lines.last().unwrap(),
+            "Normalized:  Yes (NFC, NFD, NFKC, NFKD)"
+        );
+        let lines = describe_normalization("ÅA\u{30A}");
+        assert_eq!(lines.last().unwrap(), "Normalized:  No");
+    }
+}
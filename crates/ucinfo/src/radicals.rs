@@ -0,0 +1,169 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Browsing of CJK ideographs by radical and number of residual strokes.
+
+use anyhow::{Result, bail};
+use unicodedata::{RadicalStroke, characters_with_radical, kangxi_radical};
+
+use crate::describe::{format_codepoint, glyph, printable};
+use crate::layout::{pad_right, side_by_side, text_width};
+use crate::listing::list;
+
+/// Maximum width of a row of the grid of glyphs.
+const GRID_WIDTH: usize = 80;
+
+/// Spaces between the cells of the grid of glyphs.
+const CELL_GAP: usize = 2;
+
+/// Parse a radical given either by its number in the KangXi dictionary, or as a character.
+pub(crate) fn parse_radical(radical: &str) -> Result<u8> {
+    let mut chars = radical.chars();
+    let number = match (chars.next(), chars.next()) {
+        (Some(ch), None) if !ch.is_ascii_digit() => kangxi_radical(u32::from(ch)),
+        _ => radical.parse().ok().filter(|n| (1..=214).contains(n)),
+    };
+    match number {
+        Some(number) => Ok(number),
+        None => bail!("Unknown radical: {radical}"),
+    }
+}
+
+/// Parse a number of residual strokes.
+pub(crate) fn parse_strokes(strokes: &str) -> Result<u8> {
+    match strokes.parse() {
+        Ok(strokes) => Ok(strokes),
+        Err(_) => bail!("Invalid number of strokes: {strokes}"),
+    }
+}
+
+/// Heading for the ideographs with a radical and number of residual strokes.
+fn heading(radical: u8, strokes: Option<u8>, count: usize) -> String {
+    let rs = RadicalStroke {
+        radical,
+        simplified: false,
+        strokes: strokes.unwrap_or(0),
+    };
+    let mut heading = format!("Radical {radical}");
+    if let Some(character) = rs.radical_character() {
+        heading.push_str(&format!(" {character}"));
+    }
+    if let Some(strokes) = strokes {
+        heading.push_str(&format!(" + {strokes} strokes"));
+    }
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{heading} ({count} character{plural})")
+}
+
+/// Draw the glyphs of the codepoints in a grid, each above its codepoint and the character itself
+/// (so that it can be copied), with as many cells in a row as fit in [`GRID_WIDTH`].
+fn grid(codepoints: &[u32]) -> Vec<String> {
+    let cells: Vec<Vec<String>> = codepoints
+        .iter()
+        .map(|&cp| {
+            let mut cell = glyph(cp);
+            cell.push(format!(
+                "{} {}",
+                format_codepoint(cp),
+                printable(cp).unwrap_or_default()
+            ));
+            cell
+        })
+        .collect();
+    let cell_width = cells
+        .iter()
+        .flatten()
+        .map(|line| text_width(line))
+        .max()
+        .unwrap_or(0);
+    let per_row = ((GRID_WIDTH + CELL_GAP) / (cell_width + CELL_GAP)).max(1);
+    let mut lines = Vec::new();
+    for row in cells.chunks(per_row) {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        let row = row
+            .iter()
+            .map(|cell| {
+                cell.iter()
+                    .map(|line| pad_right(line, cell_width))
+                    .collect()
+            })
+            .reduce(|left: Vec<String>, right| side_by_side(&left, &right, CELL_GAP))
+            .unwrap_or_default();
+        lines.extend(row);
+    }
+    lines
+}
+
+/// List the ideographs with a radical, and optionally a number of residual strokes, drawing them
+/// in a grid or listing them one per line.
+pub(crate) fn list_radical(radical: u8, strokes: Option<u8>, draw: bool) -> Vec<String> {
+    let codepoints: Vec<u32> = characters_with_radical(radical, strokes).collect();
+    let mut lines = vec![heading(radical, strokes, codepoints.len()), String::new()];
+    if draw {
+        lines.extend(grid(&codepoints));
+    } else {
+        lines.extend(list(codepoints));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_radical() {
+        assert_eq!(parse_radical("13").unwrap(), 13);
+        assert_eq!(parse_radical("冂").unwrap(), 13);
+        assert_eq!(parse_radical("⼌").unwrap(), 13);
+        assert!(parse_radical("0").is_err());
+        assert!(parse_radical("215").is_err());
+        assert!(parse_radical("円").is_err());
+        assert!(parse_strokes("x").is_err());
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(
+            heading(13, Some(2), 5),
+            "Radical 13 ⼌ + 2 strokes (5 characters)"
+        );
+        assert_eq!(heading(1, None, 1), "Radical 1 ⼀ (1 character)");
+    }
+
+    #[test]
+    fn test_list_radical() {
+        let lines = list_radical(13, Some(2), false);
+        assert!(lines.contains(&"U+5186    '円'  CJK UNIFIED IDEOGRAPH-5186".to_string()));
+        let lines = list_radical(13, Some(2), true);
+        assert_eq!(lines[1], "");
+        let captions = lines.iter().find(|line| line.starts_with("U+")).unwrap();
+        assert!(captions.contains("U+5186 '円'"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let height = glyph(0x5186).len() + 1;
+        assert_eq!(grid(&[0x5186, 0x5187]).len(), height);
+        let lines = grid(&[0x5186; 10]);
+        assert_eq!(lines.len(), 3 * height + 2);
+        assert!(lines.iter().all(|line| text_width(line) <= GRID_WIDTH));
+    }
+}
@@ -0,0 +1,193 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Scan of files for characters that can hide or disguise code, as in Trojan Source attacks,
+//! shown with `--scan`.
+
+use std::fs;
+
+use unicodedata::{CharacterFlag, display_name};
+
+use crate::describe::format_codepoint;
+
+/// BYTE ORDER MARK, which is expected at the start of a file.
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
+/// Exit status when suspicious characters are found.
+pub(crate) const FOUND_STATUS: i32 = 1;
+
+/// Exit status when a file cannot be read, whether or not suspicious characters were found in the
+/// others, so that errors can be told apart from findings.
+pub(crate) const ERROR_STATUS: i32 = 2;
+
+/// A suspicious character found in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Finding {
+    /// Line of the character, starting at 1.
+    pub line: usize,
+    /// Column of the character, in characters, starting at 1.
+    pub column: usize,
+    pub codepoint: u32,
+    pub flag: CharacterFlag,
+}
+
+impl Finding {
+    /// Format the finding as `path:line:column: U+XXXX NAME (Flag)`, as compilers report errors.
+    pub(crate) fn format(&self, path: &str) -> String {
+        format!(
+            "{path}:{}:{}: {} {} ({})",
+            self.line,
+            self.column,
+            format_codepoint(self.codepoint),
+            display_name(self.codepoint),
+            self.flag.name()
+        )
+    }
+}
+
+/// Find the suspicious characters in a text, in order. A byte order mark at the very start of the
+/// text is not reported.
+pub(crate) fn scan_text(text: &str) -> Vec<Finding> {
+    let text = text.strip_prefix(BYTE_ORDER_MARK).unwrap_or(text);
+    let mut findings = Vec::new();
+    for (line_idx, line) in text.split('\n').enumerate() {
+        for (column_idx, ch) in line.chars().enumerate() {
+            let codepoint = u32::from(ch);
+            if let Some(flag) = CharacterFlag::of(codepoint) {
+                findings.push(Finding {
+                    line: line_idx + 1,
+                    column: column_idx + 1,
+                    codepoint,
+                    flag,
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// Result of scanning a list of files.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ScanReport {
+    /// A line for each suspicious character found, in the order of the files.
+    pub findings: Vec<String>,
+    /// A message for each file that couldn’t be read.
+    pub errors: Vec<String>,
+}
+
+impl ScanReport {
+    /// Exit status for the scan: 0 if all is well, [FOUND_STATUS] for suspicious characters, and
+    /// [ERROR_STATUS] for files that couldn’t be read.
+    pub(crate) fn status(&self) -> i32 {
+        if !self.errors.is_empty() {
+            ERROR_STATUS
+        } else if !self.findings.is_empty() {
+            FOUND_STATUS
+        } else {
+            0
+        }
+    }
+}
+
+/// Scan files for suspicious characters. Invalid UTF-8 is replaced by U+FFFD, which is not
+/// reported. A file that cannot be read doesn’t stop the scan of the others.
+pub(crate) fn scan_files(paths: &[String]) -> ScanReport {
+    let mut report = ScanReport::default();
+    for path in paths {
+        match fs::read(path) {
+            Ok(bytes) => {
+                let text = String::from_utf8_lossy(&bytes);
+                let findings = scan_text(&text);
+                report
+                    .findings
+                    .extend(findings.iter().map(|finding| finding.format(path)));
+            }
+            Err(err) => report.errors.push(format!("Cannot read {path}: {err}")),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_text() {
+        assert!(scan_text("fn main() {}\n").is_empty());
+        let source =
+            "\u{FEFF}let access = \"user\u{202E} \u{2066}// admin\u{2069}\";\nlet pаss = 1;\n";
+        let findings = scan_text(source);
+        assert_eq!(
+            findings
+                .iter()
+                .map(|f| (f.line, f.column, f.codepoint))
+                .collect::<Vec<_>>(),
+            [
+                (1, 19, 0x202E),
+                (1, 21, 0x2066),
+                (1, 30, 0x2069),
+                (2, 6, 0x430)
+            ]
+        );
+        assert_eq!(findings[3].flag, CharacterFlag::Homoglyph);
+        assert_eq!(scan_text("a\u{FEFF}b")[0].flag, CharacterFlag::ZeroWidth);
+    }
+
+    #[test]
+    fn test_format() {
+        let finding = Finding {
+            line: 3,
+            column: 7,
+            codepoint: 0x202E,
+            flag: CharacterFlag::BidiControl,
+        };
+        assert_eq!(
+            finding.format("src/main.rs"),
+            "src/main.rs:3:7: U+202E RIGHT-TO-LEFT OVERRIDE (Bidi Control)"
+        );
+    }
+
+    #[test]
+    fn test_scan_files() {
+        let path = std::env::temp_dir().join(format!("ucinfo-scan-{}.txt", std::process::id()));
+        fs::write(&path, b"ok\nzero\xE2\x80\x8Bwidth \xFF\n").unwrap();
+        let path = path.to_string_lossy().to_string();
+        let report = scan_files(std::slice::from_ref(&path));
+        assert_eq!(
+            report.findings,
+            [format!("{path}:2:5: U+200B ZERO WIDTH SPACE (Zero Width)")]
+        );
+        assert!(report.errors.is_empty());
+        assert_eq!(report.status(), FOUND_STATUS);
+
+        // A missing file is reported without losing the findings of the others.
+        let missing = format!("{path}.missing");
+        let report = scan_files(&[missing.clone(), path.clone()]);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            report.findings,
+            [format!("{path}:2:5: U+200B ZERO WIDTH SPACE (Zero Width)")]
+        );
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].starts_with(&format!("Cannot read {missing}: ")));
+        assert_eq!(report.status(), ERROR_STATUS);
+
+        assert_eq!(scan_files(&[]).status(), 0);
+    }
+}
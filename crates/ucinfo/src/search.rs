@@ -0,0 +1,186 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Search for characters by name, optionally narrowed down by their properties.
+
+use anyhow::{Result, bail};
+use unicodedata::{
+    BinaryProperty, GeneralCategory, MajorClass, NumericValue, UnicodeVersion, age,
+    general_category, has_property, is_assigned, name, numeric_value,
+};
+
+use crate::MAX_CODEPOINT;
+
+/// A condition that characters must meet to be found by a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Filter {
+    /// The character has the given general category.
+    Category(GeneralCategory),
+    /// The general category of the character is in the given major class.
+    MajorClass(MajorClass),
+    /// The character was added in the given version of Unicode.
+    AddedIn(UnicodeVersion),
+    /// The character has the given numeric value.
+    Value(NumericValue),
+    /// The character has the given binary property.
+    Property(BinaryProperty),
+}
+
+impl Filter {
+    /// Parse the argument of `--category`, which is either a general category (`Sm`) or a major
+    /// class (`S`), by abbreviation or by name.
+    pub(crate) fn category(arg: &str) -> Result<Self> {
+        if let Some(gc) = GeneralCategory::by_name(arg) {
+            Ok(Self::Category(gc))
+        } else if let Some(class) = MajorClass::by_name(arg) {
+            Ok(Self::MajorClass(class))
+        } else {
+            bail!("Unknown general category: {arg}")
+        }
+    }
+
+    /// Parse the argument of `--added-in`, a version of Unicode such as `15.1`.
+    pub(crate) fn added_in(arg: &str) -> Result<Self> {
+        match UnicodeVersion::parse(arg) {
+            Some(version) => Ok(Self::AddedIn(version)),
+            None => bail!("Invalid Unicode version: {arg}"),
+        }
+    }
+
+    /// Parse the argument of `--value`, a number such as `5`, `1/4` or `0.25`.
+    pub(crate) fn value(arg: &str) -> Result<Self> {
+        match NumericValue::parse(arg) {
+            Some(value) => Ok(Self::Value(value)),
+            None => bail!("Invalid numeric value: {arg}"),
+        }
+    }
+
+    /// Parse the argument of `--property`, a binary property such as `WSpace` or `White_Space`.
+    pub(crate) fn property(arg: &str) -> Result<Self> {
+        match BinaryProperty::by_name(arg) {
+            Some(property) => Ok(Self::Property(property)),
+            None => bail!("Unknown binary property: {arg}"),
+        }
+    }
+
+    /// Check whether a codepoint meets the condition.
+    fn matches(&self, codepoint: u32) -> bool {
+        match *self {
+            Self::Category(gc) => general_category(codepoint) == gc,
+            Self::MajorClass(class) => general_category(codepoint).major_class() == class,
+            Self::AddedIn(version) => age(codepoint) == Some(version),
+            Self::Value(value) => numeric_value(codepoint) == Some(value),
+            Self::Property(property) => has_property(codepoint, property),
+        }
+    }
+}
+
+/// Check whether a name contains all the words of a query, ignoring case.
+fn name_matches(name: &str, words: &[String]) -> bool {
+    words.iter().all(|word| name.contains(word.as_str()))
+}
+
+/// Find the characters whose names contain all the words of a query, and which meet all the
+/// filters.
+///
+/// An empty query matches every assigned codepoint, even those without a name, so that filters
+/// can be used on their own.
+pub(crate) fn search(query: &str, filters: &[Filter]) -> Vec<u32> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_uppercase).collect();
+    (0..=MAX_CODEPOINT)
+        .filter(|&cp| is_assigned(cp))
+        .filter(|&cp| filters.iter().all(|filter| filter.matches(cp)))
+        .filter(|&cp| words.is_empty() || name(cp).is_some_and(|name| name_matches(&name, &words)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_category() {
+        assert_eq!(
+            Filter::category("Sm").unwrap(),
+            Filter::Category(GeneralCategory::MathSymbol)
+        );
+        assert_eq!(
+            Filter::category("letter").unwrap(),
+            Filter::MajorClass(MajorClass::Letter)
+        );
+        assert!(Filter::category("Xx").is_err());
+    }
+
+    #[test]
+    fn test_filter_added_in() {
+        let filter = Filter::added_in("2.1").unwrap();
+        assert_eq!(
+            filter,
+            Filter::AddedIn(UnicodeVersion { major: 2, minor: 1 })
+        );
+        assert!(filter.matches(0x20AC));
+        assert!(!filter.matches(0x41));
+        assert_eq!(Filter::added_in("2.1.0").unwrap(), filter);
+        assert!(Filter::added_in("2.1.2").is_err());
+        assert!(Filter::added_in("two").is_err());
+    }
+
+    #[test]
+    fn test_filter_value() {
+        let filter = Filter::value("5").unwrap();
+        assert!(filter.matches(0x35));
+        assert!(filter.matches(0x0F25)); // TIBETAN DIGIT FIVE
+        assert!(filter.matches(0x4E94)); // 五
+        assert!(!filter.matches(0x36));
+        assert!(Filter::value("0.5").unwrap().matches(0xBD)); // ½
+        assert!(Filter::value("five").is_err());
+    }
+
+    #[test]
+    fn test_filter_property() {
+        let filter = Filter::property("White_Space").unwrap();
+        assert_eq!(filter, Filter::Property(BinaryProperty::WhiteSpace));
+        assert!(filter.matches(0x3000));
+        assert!(!filter.matches(0x41));
+        assert!(Filter::property("Nonsense").is_err());
+        let dashes = search("", &[Filter::property("Dash").unwrap()]);
+        assert!(dashes.contains(&0x2D));
+        assert!(dashes.contains(&0x2014));
+    }
+
+    #[test]
+    fn test_search() {
+        let arrows = search("rightwards arrow", &[]);
+        assert!(arrows.contains(&0x2192));
+        assert!(arrows.contains(&0x21D2));
+        let math = search("rightwards arrow", &[Filter::category("Sm").unwrap()]);
+        assert!(math.contains(&0x2192));
+        assert!(!math.contains(&0x2794)); // HEAVY WIDE-HEADED RIGHTWARDS ARROW is So
+        assert!(math.iter().all(|cp| arrows.contains(cp)));
+        assert_eq!(search("latin capital letter a", &[])[0], 0x41);
+        assert!(search("no such character", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_search_filters_only() {
+        let controls = search("", &[Filter::category("Cc").unwrap()]);
+        assert_eq!(controls.len(), 65);
+        let added = search("", &[Filter::added_in("2.1").unwrap()]);
+        assert_eq!(added, [0x20AC, 0xFFFC]);
+    }
+}
@@ -0,0 +1,328 @@
+//! Reader for the XML representation of the Unicode Character Database (UAX #42), used by the
+//! build script of the `unicodedata` crate to generate its tables.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+
+fn get_attrs<'a>(elem: &'a BytesStart<'a>) -> impl Iterator<Item = (String, String)> + 'a {
+    elem.attributes().map(|a| {
+        let Attribute { key, value } = a.unwrap();
+        (
+            unsafe { String::from_utf8_unchecked(key.0.to_vec()) },
+            unsafe { String::from_utf8_unchecked(value.to_vec()) },
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameAlias {
+    pub alias: String,
+    pub type_: String,
+}
+
+/// An element of the repertoire (`char`, `noncharacter`, `reserved` or `surrogate`), with the
+/// attributes of its group already merged into its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepertoireItem {
+    pub tag: String,
+    pub attrs: HashMap<String, String>,
+    pub name_aliases: Vec<NameAlias>,
+}
+
+impl RepertoireItem {
+    /// Get the first and last codepoints covered by the item.
+    pub fn get_codepoint_range(&self) -> Result<(u32, u32)> {
+        let start_cp_str = self
+            .attrs
+            .get("cp")
+            .or_else(|| self.attrs.get("first-cp"))
+            .ok_or_else(|| anyhow::anyhow!("No codepoint found"))?;
+        let start_cp = u32::from_str_radix(start_cp_str, 16)?;
+
+        let end_cp = if let Some(end_cp_str) = self.attrs.get("last-cp") {
+            u32::from_str_radix(end_cp_str, 16)?
+        } else {
+            start_cp
+        };
+
+        Ok((start_cp, end_cp))
+    }
+
+    /// Get the value of an attribute.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.get(name).map(String::as_str)
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for RepertoireItem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let (self_cp, _) = self.get_codepoint_range().ok()?;
+        let (other_cp, _) = other.get_codepoint_range().ok()?;
+        Some(self_cp.cmp(&other_cp))
+    }
+}
+
+impl Ord for RepertoireItem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+/// A block of the UCD, as listed in the `blocks` element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockItem {
+    pub first: u32,
+    pub last: u32,
+    pub name: String,
+}
+
+fn process_blocks(reader: &mut Reader<BufReader<File>>) -> Result<Vec<BlockItem>> {
+    let mut blocks = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(element) | Event::Empty(element)
+                if element.name().as_ref() == b"block" =>
+            {
+                let attrs: HashMap<String, String> = get_attrs(&element).collect();
+                let (Some(first), Some(last), Some(name)) = (
+                    attrs.get("first-cp"),
+                    attrs.get("last-cp"),
+                    attrs.get("name"),
+                ) else {
+                    bail!("Incomplete block element: {attrs:?}");
+                };
+                blocks.push(BlockItem {
+                    first: u32::from_str_radix(first, 16)?,
+                    last: u32::from_str_radix(last, 16)?,
+                    name: name.clone(),
+                });
+            }
+            Event::End(element) if element.name().as_ref() == b"blocks" => break,
+            Event::Eof => bail!("Unexpected end of file"),
+            _ => {}
+        }
+    }
+
+    blocks.sort_by_key(|block| block.first);
+
+    Ok(blocks)
+}
+
+fn process_repertoire(reader: &mut Reader<BufReader<File>>) -> Result<Vec<RepertoireItem>> {
+    let mut items = Vec::new();
+
+    let mut buf = Vec::new();
+    let mut group_attrs = HashMap::<String, String>::new();
+    let mut item: Option<RepertoireItem> = None;
+
+    loop {
+        buf.clear();
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(element) | Event::Empty(element) => match element.name().as_ref() {
+                b"group" => {
+                    group_attrs.extend(get_attrs(&element));
+                }
+                b"char" | b"noncharacter" | b"reserved" | b"surrogate" => {
+                    if let Some(item) = item.take() {
+                        items.push(item);
+                    }
+                    let mut attrs = group_attrs.clone();
+                    attrs.extend(get_attrs(&element));
+                    item = Some(RepertoireItem {
+                        tag: String::from_utf8(element.name().as_ref().to_vec()).unwrap(),
+                        attrs,
+                        name_aliases: Vec::new(),
+                    });
+                }
+                b"name-alias" => {
+                    if let Some(ref mut item) = item {
+                        let mut alias = String::new();
+                        let mut type_ = String::new();
+                        for (key, value) in get_attrs(&element) {
+                            match key.as_str() {
+                                "alias" => alias = value,
+                                "type" => type_ = value,
+                                _ => {
+                                    bail!("Unexpected attribute '{}' in name-alias", key);
+                                }
+                            }
+                        }
+                        item.name_aliases.push(NameAlias { alias, type_ });
+                    } else {
+                        bail!("name-alias found outside of char/noncharacter/reserved/surrogate");
+                    }
+                }
+                _ => {}
+            },
+            Event::End(element) => match element.name().as_ref() {
+                b"repertoire" => break,
+                b"group" => group_attrs.clear(),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    if let Some(item) = item.take() {
+        items.push(item);
+    }
+
+    items.sort();
+
+    Ok(items)
+}
+
+/// Contents of the Unicode Character Database, as read from its XML representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ucd {
+    pub description: String,
+    pub repertoire: Vec<RepertoireItem>,
+    pub blocks: Vec<BlockItem>,
+}
+
+impl Ucd {
+    /// Read the UCD from one of the XML files described in UAX #42.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut reader = Reader::from_file(path)?;
+        let mut buf = Vec::new();
+
+        let mut description = String::new();
+        let mut items: Option<Vec<_>> = None;
+        let mut blocks = Vec::new();
+
+        loop {
+            buf.clear();
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(element) => {
+                    if let b"ucd" = element.name().as_ref() {
+                        let mut buf = Vec::new();
+                        loop {
+                            buf.clear();
+                            match reader.read_event_into(&mut buf)? {
+                                Event::Start(element) => match element.name().as_ref() {
+                                    b"description" => {
+                                        let mut buf = Vec::new();
+                                        if let Event::Text(text) =
+                                            reader.read_event_into(&mut buf)?
+                                        {
+                                            description = text.decode()?.trim().to_string();
+                                        }
+                                    }
+                                    b"repertoire" => {
+                                        items = Some(process_repertoire(&mut reader)?);
+                                    }
+                                    b"blocks" => {
+                                        blocks = process_blocks(&mut reader)?;
+                                    }
+                                    _ => {}
+                                },
+                                #[allow(clippy::collapsible_match)]
+                                Event::End(element) => {
+                                    if element.name().as_ref() == b"ucd" {
+                                        break;
+                                    }
+                                }
+                                Event::Eof => bail!("Unexpected end of file"),
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let Some(repertoire) = items else {
+            bail!("No repertoire found");
+        };
+
+        Ok(Self {
+            description,
+            repertoire,
+            blocks,
+        })
+    }
+
+    /// Find the repertoire item covering a codepoint.
+    pub fn item(&self, codepoint: u32) -> Option<&RepertoireItem> {
+        let idx = self.repertoire.partition_point(|item| {
+            item.get_codepoint_range()
+                .is_ok_and(|(_, last)| last < codepoint)
+        });
+        self.repertoire.get(idx).filter(|item| {
+            item.get_codepoint_range()
+                .is_ok_and(|(first, _)| first <= codepoint)
+        })
+    }
+}
+
+/// Build a table of ranges of codepoints sharing the same value, as given by a function of each
+/// repertoire item.
+///
+/// Items for which the function returns `None` are left out, and adjacent ranges with the same
+/// value are merged. The table is sorted by codepoint, so it can be binary searched.
+pub fn range_table<V, F>(items: &[RepertoireItem], mut value: F) -> Result<Vec<(u32, u32, V)>>
+where
+    V: PartialEq,
+    F: FnMut(&RepertoireItem) -> Option<V>,
+{
+    let mut table: Vec<(u32, u32, V)> = Vec::new();
+    for item in items {
+        let Some(value) = value(item) else {
+            continue;
+        };
+        let (first, last) = item.get_codepoint_range()?;
+        match table.last_mut() {
+            Some((_, prev_last, prev_value)) if *prev_last + 1 == first && *prev_value == value => {
+                *prev_last = last;
+            }
+            _ => table.push((first, last, value)),
+        }
+    }
+    Ok(table)
+}
+
+/// Build a table of ranges of codepoints with the given value of an attribute.
+///
+/// Items lacking the attribute, or whose value is `default`, are left out.
+pub fn attr_table(
+    items: &[RepertoireItem],
+    attr: &str,
+    default: &str,
+) -> Result<Vec<(u32, u32, String)>> {
+    range_table(items, |item| {
+        item.attr(attr)
+            .filter(|&value| value != default)
+            .map(str::to_string)
+    })
+}
+
+/// Build a table of ranges of codepoints with the boolean attributes that are set (`Y`) packed
+/// into a bitset, where bit `n` stands for `attrs[n]`.
+///
+/// Items with none of the attributes set are left out.
+pub fn bitset_table(items: &[RepertoireItem], attrs: &[&str]) -> Result<Vec<(u32, u32, u64)>> {
+    if attrs.len() > 64 {
+        bail!("Too many attributes for a 64-bit set: {}", attrs.len());
+    }
+    range_table(items, |item| {
+        let bits = attrs
+            .iter()
+            .enumerate()
+            .filter(|&(_, attr)| item.attr(attr) == Some("Y"))
+            .fold(0u64, |bits, (bit, _)| bits | 1 << bit);
+        (bits != 0).then_some(bits)
+    })
+}
@@ -0,0 +1,532 @@
+use anyhow::anyhow;
+use std::path::Path;
+use std::{env, fs, path};
+use unicodedata_build::{RepertoireItem, Ucd, attr_table, bitset_table, range_table};
+
+fn output_file_path<P: AsRef<Path>>(filename: P) -> anyhow::Result<path::PathBuf> {
+    let binding = env::var_os("OUT_DIR").ok_or_else(|| anyhow!("OUT_DIR not set"))?;
+    Ok(path::Path::new(&binding).join(filename))
+}
+
+/// Save a table to a binary file, and export its path in the `UNICODEDATA_<NAME>_FILE`
+/// environment variable.
+fn save_table<T: bincode::Encode>(table: &T, name: &str) -> anyhow::Result<()> {
+    let output_file = output_file_path(format!("{name}.bin"))?;
+    let encoded = bincode::encode_to_vec(table, bincode::config::standard())?;
+    fs::write(&output_file, encoded)?;
+    println!(
+        "cargo:rustc-env=UNICODEDATA_{}_FILE={}",
+        name.to_uppercase(),
+        output_file.display()
+    );
+    Ok(())
+}
+
+/// Save a table like [`save_table`], but compressed with DEFLATE, for tables that would make the
+/// binary much larger.
+#[cfg(feature = "unihan")]
+fn save_compressed_table<T: bincode::Encode>(table: &T, name: &str) -> anyhow::Result<()> {
+    use flate2::{Compression, write::DeflateEncoder};
+    use std::io::Write;
+
+    let output_file = output_file_path(format!("{name}.bin.deflate"))?;
+    let encoded = bincode::encode_to_vec(table, bincode::config::standard())?;
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(&encoded)?;
+    fs::write(&output_file, encoder.finish()?)?;
+    println!(
+        "cargo:rustc-env=UNICODEDATA_{}_FILE={}",
+        name.to_uppercase(),
+        output_file.display()
+    );
+    Ok(())
+}
+
+/// Save the Unicode version to a text file.
+fn save_unicode_version(metadata_file: &Path) -> anyhow::Result<String> {
+    let contents = fs::read_to_string(metadata_file)?;
+    let metadata: serde_json::Value = serde_json::from_str(&contents)?;
+    let version = metadata["version"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Missing version in metadata"))?;
+    let version_file = output_file_path("unicode_version.txt")?;
+    fs::write(&version_file, version)?;
+    Ok(version_file.to_string_lossy().to_string())
+}
+
+/// Table of character names. Names of ranges of characters (e.g. CJK ideographs) contain a `#`,
+/// which stands for the codepoint.
+fn names_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, u32, String)>> {
+    range_table(items, |item| {
+        item.attr("na")
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    })
+}
+
+/// Table of blocks, with their names and the short aliases used by the `blk` property.
+fn blocks_table(ucd: &Ucd) -> Vec<(u32, u32, String, String)> {
+    ucd.blocks
+        .iter()
+        .map(|block| {
+            let alias = ucd
+                .item(block.first)
+                .and_then(|item| item.attr("blk"))
+                .unwrap_or_default();
+            (
+                block.first,
+                block.last,
+                block.name.clone(),
+                alias.to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Table of script extensions, as space-separated lists of script codes. Codepoints whose
+/// extensions are just their script are left out.
+fn script_extensions_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, u32, String)>> {
+    range_table(items, |item| {
+        item.attr("scx")
+            .filter(|&scx| Some(scx) != item.attr("sc"))
+            .map(str::to_string)
+    })
+}
+
+/// Parse a space-separated list of codepoints, as used by the UCD for mappings.
+fn parse_codepoints(value: &str) -> anyhow::Result<Vec<u32>> {
+    value
+        .split_whitespace()
+        .map(|cp| Ok(u32::from_str_radix(cp, 16)?))
+        .collect()
+}
+
+/// Table of decomposition mappings (`dm`), each with its decomposition type (`dt`). Hangul
+/// syllables are left out, as their decompositions are derived algorithmically.
+fn decomposition_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, String, Vec<u32>)>> {
+    let mut table = Vec::new();
+    for item in items {
+        let (Some(dt), Some(dm)) = (item.attr("dt"), item.attr("dm")) else {
+            continue;
+        };
+        if dt == "none" || dm == "#" {
+            continue;
+        }
+        let mapping = parse_codepoints(dm)?;
+        let (first, last) = item.get_codepoint_range()?;
+        for codepoint in first..=last {
+            if !(0xAC00..=0xD7A3).contains(&codepoint) {
+                table.push((codepoint, dt.to_string(), mapping.clone()));
+            }
+        }
+    }
+    Ok(table)
+}
+
+/// Table of canonical combining classes (`ccc`). Codepoints with class 0 are left out.
+fn combining_class_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, u32, u8)>> {
+    range_table(items, |item| {
+        item.attr("ccc")
+            .and_then(|ccc| ccc.parse().ok())
+            .filter(|&ccc| ccc != 0)
+    })
+}
+
+/// Table of primary composites, as `(first, second, composite)`, sorted by the pair of characters
+/// they are composed from. These are the characters with a canonical decomposition into two
+/// characters that aren’t excluded from composition (`Comp_Ex`).
+fn composition_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, u32, u32)>> {
+    let mut table = Vec::new();
+    for item in items {
+        if item.attr("dt") != Some("can") || item.attr("Comp_Ex") == Some("Y") {
+            continue;
+        }
+        let Some(dm) = item.attr("dm").filter(|&dm| dm != "#") else {
+            continue;
+        };
+        if let [first, second] = parse_codepoints(dm)?[..] {
+            let (composite, _) = item.get_codepoint_range()?;
+            table.push((first, second, composite));
+        }
+    }
+    table.sort();
+    Ok(table)
+}
+
+/// Boolean attributes of the UCD XML stored in the binary properties bitset, in bit order.
+///
+/// The order is that of `BinaryProperty` (sorted by property name), whose discriminants are the
+/// bits.
+const BINARY_PROPERTIES: &[&str] = &[
+    "AHex",
+    "Alpha",
+    "Bidi_C",
+    "Bidi_M",
+    "CI",
+    "Cased",
+    "CWCF",
+    "CWCM",
+    "CWL",
+    "CWKCF",
+    "CWT",
+    "CWU",
+    "CE",
+    "Dash",
+    "DI",
+    "Dep",
+    "Dia",
+    "Emoji",
+    "EComp",
+    "EMod",
+    "EBase",
+    "EPres",
+    "ExtPict",
+    "Ext",
+    "Comp_Ex",
+    "Gr_Base",
+    "Gr_Ext",
+    "Hex",
+    "Hyphen",
+    "ID_Compat_Math_Continue",
+    "ID_Compat_Math_Start",
+    "IDC",
+    "IDS",
+    "IDSB",
+    "IDST",
+    "IDSU",
+    "Ideo",
+    "Join_C",
+    "LOE",
+    "Lower",
+    "Math",
+    "MCM",
+    "NChar",
+    "Pat_Syn",
+    "Pat_WS",
+    "PCM",
+    "QMark",
+    "Radical",
+    "RI",
+    "STerm",
+    "SD",
+    "Term",
+    "UIdeo",
+    "Upper",
+    "VS",
+    "WSpace",
+    "XIDC",
+    "XIDS",
+];
+
+/// Table of the codepoints for which a codepoint-sequence attribute (e.g. `uc`) maps to something
+/// other than the codepoint itself (`#`). Empty values, meaning no mapping (e.g. `bmg`), are also
+/// left out.
+fn mapping_table(items: &[RepertoireItem], attr: &str) -> anyhow::Result<Vec<(u32, Vec<u32>)>> {
+    let mut table = Vec::new();
+    for item in items {
+        let Some(value) = item
+            .attr(attr)
+            .filter(|&value| value != "#" && !value.is_empty())
+        else {
+            continue;
+        };
+        let mapping = parse_codepoints(value)?;
+        let (first, last) = item.get_codepoint_range()?;
+        table.extend((first..=last).map(|codepoint| (codepoint, mapping.clone())));
+    }
+    Ok(table)
+}
+
+/// Table of the codepoints for which a single-codepoint attribute (e.g. `suc`) maps to another
+/// codepoint.
+fn simple_mapping_table(items: &[RepertoireItem], attr: &str) -> anyhow::Result<Vec<(u32, u32)>> {
+    mapping_table(items, attr)?
+        .into_iter()
+        .map(|(codepoint, mapping)| match mapping[..] {
+            [mapped] => Ok((codepoint, mapped)),
+            _ => Err(anyhow!("U+{codepoint:04X}: invalid {attr} mapping")),
+        })
+        .collect()
+}
+
+/// Replace the `\x{XX}` escapes used in the descriptions of the emoji data files (e.g.
+/// `keycap: \x{23}`) with the characters they stand for.
+fn unescape_description(description: &str) -> anyhow::Result<String> {
+    let mut out = String::new();
+    let mut rest = description;
+    while let Some(start) = rest.find("\\x{") {
+        out.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("Unterminated escape: {description}"))?;
+        let hex = &rest[start + 3..start + end];
+        let ch = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| anyhow!("Invalid escape: {description}"))?;
+        out.push(ch);
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Parse the emoji sequences of `emoji-sequences.txt` or `emoji-zwj-sequences.txt`, as the
+/// codepoints, the type field and the description of each.
+///
+/// Lines with a single codepoint, or a range of them, are left out, as those are described by
+/// the character names.
+fn parse_emoji_sequences(contents: &str) -> anyhow::Result<Vec<(Vec<u32>, String, String)>> {
+    let mut table = Vec::new();
+    for line in contents.lines() {
+        let data = line.split('#').next().unwrap_or_default().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = data.split(';').map(str::trim).collect();
+        let [codepoints, kind, description] = fields[..] else {
+            anyhow::bail!("Invalid emoji sequence line: {line}");
+        };
+        if codepoints.contains("..") {
+            continue;
+        }
+        let codepoints = parse_codepoints(codepoints)?;
+        if codepoints.len() > 1 {
+            table.push((
+                codepoints,
+                kind.to_string(),
+                unescape_description(description)?,
+            ));
+        }
+    }
+    Ok(table)
+}
+
+/// Load the emoji sequences from all the emoji data files, sorted by codepoints.
+fn emoji_sequences_table(
+    files: &[path::PathBuf],
+) -> anyhow::Result<Vec<(Vec<u32>, String, String)>> {
+    let mut table = Vec::new();
+    for file in files {
+        table.extend(parse_emoji_sequences(&fs::read_to_string(file)?)?);
+    }
+    table.sort();
+    Ok(table)
+}
+
+/// Index of the emoji sequences by their codepoints without emoji presentation selectors (VS16),
+/// as `(codepoints, index in the sequences table)`, to find sequences typed without them. Sorted by
+/// codepoints, then by index.
+fn unqualified_emoji_table(sequences: &[(Vec<u32>, String, String)]) -> Vec<(Vec<u32>, u32)> {
+    let mut table: Vec<(Vec<u32>, u32)> = sequences
+        .iter()
+        .enumerate()
+        .map(|(idx, (codepoints, _, _))| {
+            let unqualified = codepoints.iter().copied().filter(|&cp| cp != 0xFE0F);
+            (unqualified.collect(), idx as u32)
+        })
+        .collect();
+    table.sort();
+    table
+}
+
+/// Table of the confusables of UTS #39: characters mapped to the prototype they can be mistaken
+/// for, sorted by codepoint.
+fn confusables_table(file: &Path) -> anyhow::Result<Vec<(u32, Vec<u32>)>> {
+    let contents = fs::read_to_string(file)?;
+    let mut table = Vec::new();
+    for line in contents.trim_start_matches('\u{FEFF}').lines() {
+        let data = line.split('#').next().unwrap_or_default().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = data.split(';').map(str::trim).collect();
+        let [source, prototype, _] = fields[..] else {
+            anyhow::bail!("Invalid confusables line: {line}");
+        };
+        let source = u32::from_str_radix(source, 16)?;
+        table.push((source, parse_codepoints(prototype)?));
+    }
+    table.sort();
+    Ok(table)
+}
+
+/// Table of the ranges of codepoints in a data file with lines like `0041..005A ; Value`, such as
+/// the identifier data of UTS #39. Codepoints not listed keep the default value of the property.
+fn ranges_file_table(file: &Path) -> anyhow::Result<Vec<(u32, u32, String)>> {
+    let contents = fs::read_to_string(file)?;
+    let mut table = Vec::new();
+    for line in contents.trim_start_matches('\u{FEFF}').lines() {
+        let data = line.split('#').next().unwrap_or_default().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let Some((range, value)) = data.split_once(';') else {
+            anyhow::bail!("Invalid line in {}: {line}", file.display());
+        };
+        let range = range.trim();
+        let (first, last) = range.split_once("..").unwrap_or((range, range));
+        table.push((
+            u32::from_str_radix(first, 16)?,
+            u32::from_str_radix(last, 16)?,
+            value.trim().to_string(),
+        ));
+    }
+    table.sort();
+    Ok(table)
+}
+
+/// Unihan fields included in the Unihan table.
+#[cfg(feature = "unihan")]
+const UNIHAN_FIELDS: &[&str] = &[
+    "kDefinition",
+    "kMandarin",
+    "kCantonese",
+    "kJapaneseOn",
+    "kJapaneseKun",
+    "kHangul",
+    "kTotalStrokes",
+    "kRSUnicode",
+];
+
+/// Unihan fields of an ideograph, as pairs of field name and raw value.
+#[cfg(feature = "unihan")]
+type UnihanFields = Vec<(String, String)>;
+
+/// Table of the Unihan fields of each ideograph.
+#[cfg(feature = "unihan")]
+fn unihan_table(items: &[RepertoireItem]) -> anyhow::Result<Vec<(u32, UnihanFields)>> {
+    let mut table = Vec::new();
+    for item in items {
+        let fields: UnihanFields = UNIHAN_FIELDS
+            .iter()
+            .filter_map(|&field| {
+                let value = item.attr(field).filter(|value| !value.is_empty())?;
+                Some((field.to_string(), value.to_string()))
+            })
+            .collect();
+        if fields.is_empty() {
+            continue;
+        }
+        let (first, last) = item.get_codepoint_range()?;
+        for codepoint in first..=last {
+            table.push((codepoint, fields.clone()));
+        }
+    }
+    table.sort_by_key(|&(codepoint, _)| codepoint);
+    Ok(table)
+}
+
+fn main() -> anyhow::Result<()> {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("data")
+        .join("unicodedata");
+
+    if !data_dir.exists() {
+        return Err(anyhow::anyhow!(
+            "Data directory does not exist: {}",
+            data_dir.display()
+        ));
+    }
+
+    let metadata_file = data_dir.join("metadata.json");
+    let ucd_file = data_dir.join("ucd.nounihan.grouped.xml");
+
+    let version_file = save_unicode_version(&metadata_file)?;
+    let ucd = Ucd::from_file(&ucd_file)?;
+    let items = &ucd.repertoire;
+
+    save_table(&names_table(items)?, "names")?;
+    save_table(&attr_table(items, "gc", "Cn")?, "general_category")?;
+    save_table(&attr_table(items, "ea", "N")?, "east_asian_width")?;
+    save_table(&BINARY_PROPERTIES, "binary_property_names")?;
+    save_table(
+        &bitset_table(items, BINARY_PROPERTIES)?,
+        "binary_properties",
+    )?;
+    save_table(&blocks_table(&ucd), "blocks")?;
+    save_table(&attr_table(items, "sc", "Zzzz")?, "script")?;
+    save_table(&script_extensions_table(items)?, "script_extensions")?;
+    save_table(&attr_table(items, "age", "unassigned")?, "age")?;
+    save_table(&decomposition_table(items)?, "decomposition")?;
+    save_table(&combining_class_table(items)?, "combining_class")?;
+    save_table(&composition_table(items)?, "composition")?;
+    save_table(&attr_table(items, "NFC_QC", "Y")?, "nfc_quick_check")?;
+    save_table(&attr_table(items, "NFD_QC", "Y")?, "nfd_quick_check")?;
+    save_table(&attr_table(items, "NFKC_QC", "Y")?, "nfkc_quick_check")?;
+    save_table(&attr_table(items, "NFKD_QC", "Y")?, "nfkd_quick_check")?;
+    save_table(&simple_mapping_table(items, "suc")?, "simple_uppercase")?;
+    save_table(&simple_mapping_table(items, "slc")?, "simple_lowercase")?;
+    save_table(&simple_mapping_table(items, "stc")?, "simple_titlecase")?;
+    save_table(&simple_mapping_table(items, "scf")?, "simple_case_folding")?;
+    save_table(&mapping_table(items, "uc")?, "uppercase")?;
+    save_table(&mapping_table(items, "lc")?, "lowercase")?;
+    save_table(&mapping_table(items, "tc")?, "titlecase")?;
+    save_table(&mapping_table(items, "cf")?, "case_folding")?;
+    save_table(&attr_table(items, "bc", "L")?, "bidi_class")?;
+    save_table(&simple_mapping_table(items, "bmg")?, "bidi_mirroring_glyph")?;
+    save_table(&attr_table(items, "bpt", "n")?, "bidi_paired_bracket_type")?;
+    save_table(&simple_mapping_table(items, "bpb")?, "bidi_paired_bracket")?;
+    save_table(&attr_table(items, "lb", "XX")?, "line_break")?;
+    save_table(&attr_table(items, "WB", "XX")?, "word_break")?;
+    save_table(&attr_table(items, "SB", "XX")?, "sentence_break")?;
+    save_table(&attr_table(items, "GCB", "XX")?, "grapheme_cluster_break")?;
+    save_table(&attr_table(items, "InCB", "None")?, "indic_conjunct_break")?;
+    save_table(&attr_table(items, "JSN", "")?, "jamo_short_name")?;
+    save_table(&attr_table(items, "nt", "None")?, "numeric_type")?;
+    save_table(&attr_table(items, "nv", "NaN")?, "numeric_value")?;
+
+    let emoji_dir = data_dir.parent().unwrap().join("emoji");
+    let emoji_files = [
+        emoji_dir.join("emoji-sequences.txt"),
+        emoji_dir.join("emoji-zwj-sequences.txt"),
+    ];
+    let emoji_sequences = emoji_sequences_table(&emoji_files)?;
+    save_table(&emoji_sequences, "emoji_sequences")?;
+    save_table(
+        &unqualified_emoji_table(&emoji_sequences),
+        "unqualified_emoji",
+    )?;
+
+    let security_dir = data_dir.parent().unwrap().join("security");
+    let confusables_file = security_dir.join("confusables.txt");
+    save_table(&confusables_table(&confusables_file)?, "confusables")?;
+    let identifier_files = [
+        security_dir.join("IdentifierStatus.txt"),
+        security_dir.join("IdentifierType.txt"),
+    ];
+    save_table(
+        &ranges_file_table(&identifier_files[0])?,
+        "identifier_status",
+    )?;
+    save_table(&ranges_file_table(&identifier_files[1])?, "identifier_type")?;
+
+    #[cfg(feature = "unihan")]
+    {
+        let unihan_file = data_dir
+            .parent()
+            .unwrap()
+            .join("unihan")
+            .join("ucd.unihan.grouped.xml");
+        let unihan = Ucd::from_file(&unihan_file)?;
+        save_compressed_table(&unihan_table(&unihan.repertoire)?, "unihan")?;
+        println!("cargo:rerun-if-changed={}", unihan_file.display());
+    }
+
+    println!("cargo:rerun-if-changed={}", metadata_file.display());
+    println!("cargo:rerun-if-changed={}", ucd_file.display());
+    for file in &emoji_files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+    println!("cargo:rerun-if-changed={}", confusables_file.display());
+    for file in &identifier_files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+    println!("cargo:rustc-env=UNICODEDATA_VERSION_FILE={version_file}");
+
+    Ok(())
+}
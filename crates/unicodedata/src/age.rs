@@ -0,0 +1,197 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use std::fmt;
+
+use crate::tables::{AGE, lookup};
+
+/// Versions of Unicode that added characters, with the year and month they were released.
+const VERSIONS: &[(u8, u8, u16, u8)] = &[
+    (1, 1, 1993, 6),
+    (2, 0, 1996, 7),
+    (2, 1, 1998, 5),
+    (3, 0, 1999, 9),
+    (3, 1, 2001, 3),
+    (3, 2, 2002, 3),
+    (4, 0, 2003, 4),
+    (4, 1, 2005, 3),
+    (5, 0, 2006, 7),
+    (5, 1, 2008, 4),
+    (5, 2, 2009, 10),
+    (6, 0, 2010, 10),
+    (6, 1, 2012, 1),
+    (6, 2, 2012, 9),
+    (6, 3, 2013, 9),
+    (7, 0, 2014, 6),
+    (8, 0, 2015, 6),
+    (9, 0, 2016, 6),
+    (10, 0, 2017, 6),
+    (11, 0, 2018, 6),
+    (12, 0, 2019, 3),
+    (12, 1, 2019, 5),
+    (13, 0, 2020, 3),
+    (14, 0, 2021, 9),
+    (15, 0, 2022, 9),
+    (15, 1, 2023, 9),
+    (16, 0, 2024, 9),
+    (17, 0, 2025, 9),
+];
+
+/// Names of the months, for formatting release dates.
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A version of the Unicode Standard, as used by the `Age` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl UnicodeVersion {
+    /// Parse a version in the form `major.minor` (e.g. `6.1`); a single number stands for
+    /// `major.0`. The three-part form `major.minor.0` (e.g. `15.1.0`) is accepted too, but not
+    /// other update versions, which the `Age` property doesn’t distinguish.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+        if !matches!(parts.next(), None | Some("0")) || parts.next().is_some() {
+            return None;
+        }
+        Some(Self { major, minor })
+    }
+
+    /// Iterate over the versions of Unicode known to the library, from the oldest to the newest.
+    pub fn all() -> impl ExactSizeIterator<Item = Self> {
+        VERSIONS
+            .iter()
+            .map(|&(major, minor, _, _)| Self { major, minor })
+    }
+
+    /// Release date of the version, if known.
+    pub fn release_date(self) -> Option<ReleaseDate> {
+        VERSIONS
+            .iter()
+            .find(|&&(major, minor, _, _)| (major, minor) == (self.major, self.minor))
+            .map(|&(_, _, year, month)| ReleaseDate { year, month })
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Year and month when a version of Unicode was released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReleaseDate {
+    pub year: u16,
+    /// Month, from 1 to 12.
+    pub month: u8,
+}
+
+impl fmt::Display for ReleaseDate {
+    /// Format the date as the month name followed by the year, e.g. `June 1993`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", MONTHS[usize::from(self.month) - 1], self.year)
+    }
+}
+
+/// Get the version of Unicode in which a codepoint was assigned (its `Age` property), or `None`
+/// if it is unassigned.
+pub fn age(codepoint: u32) -> Option<UnicodeVersion> {
+    lookup(&AGE, codepoint).and_then(|version| UnicodeVersion::parse(version))
+}
+
+/// Iterate over the codepoints assigned in a given version of Unicode, in order.
+pub fn characters_added_in(version: UnicodeVersion) -> impl Iterator<Item = u32> {
+    AGE.iter()
+        .filter(move |(_, _, age)| UnicodeVersion::parse(age) == Some(version))
+        .flat_map(|&(first, last, _)| first..=last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions_sorted() {
+        let versions: Vec<UnicodeVersion> = UnicodeVersion::all().collect();
+        assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(
+            VERSIONS
+                .windows(2)
+                .all(|pair| (pair[0].2, pair[0].3) <= (pair[1].2, pair[1].3))
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let v = |major, minor| Some(UnicodeVersion { major, minor });
+        assert_eq!(UnicodeVersion::parse("1.1"), v(1, 1));
+        assert_eq!(UnicodeVersion::parse("15"), v(15, 0));
+        assert_eq!(UnicodeVersion::parse("unassigned"), None);
+        assert_eq!(UnicodeVersion::parse("6.x"), None);
+        assert_eq!(UnicodeVersion::parse("15.1.0"), v(15, 1));
+        assert_eq!(UnicodeVersion::parse("15.1.1"), None);
+        assert_eq!(UnicodeVersion::parse("15.1.0.0"), None);
+        assert_eq!(UnicodeVersion::parse("15."), None);
+        assert_eq!(v(12, 1).unwrap().to_string(), "12.1");
+    }
+
+    #[test]
+    fn test_release_date() {
+        let v1_1 = UnicodeVersion::parse("1.1").unwrap();
+        assert_eq!(v1_1.release_date().unwrap().to_string(), "June 1993");
+        let v6_1 = UnicodeVersion::parse("6.1").unwrap();
+        assert_eq!(v6_1.release_date().unwrap().to_string(), "January 2012");
+        assert_eq!(UnicodeVersion::parse("1.0").unwrap().release_date(), None);
+    }
+
+    #[test]
+    fn test_age() {
+        assert_eq!(age(0x41), UnicodeVersion::parse("1.1"));
+        assert_eq!(age(0x20AC), UnicodeVersion::parse("2.1")); // EURO SIGN
+        assert_eq!(age(0x1F44D), UnicodeVersion::parse("6.0"));
+        assert_eq!(age(0x0378), None);
+    }
+
+    #[test]
+    fn test_characters_added_in() {
+        let v2_1 = UnicodeVersion::parse("2.1").unwrap();
+        let added: Vec<u32> = characters_added_in(v2_1).collect();
+        assert!(added.contains(&0x20AC));
+        assert!(added.contains(&0xFFFC));
+        assert!(added.iter().all(|&cp| age(cp) == Some(v2_1)));
+        assert_eq!(added.len(), 2);
+    }
+}
@@ -0,0 +1,262 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::properties::{BinaryProperty, has_property};
+use crate::tables::{
+    BIDI_CLASS, BIDI_MIRRORING_GLYPH, BIDI_PAIRED_BRACKET, BIDI_PAIRED_BRACKET_TYPE, lookup,
+    lookup_codepoint,
+};
+
+/// Value of the Bidi_Class property, used by the Unicode Bidirectional Algorithm (UAX #9).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    LeftToRight,
+    RightToLeft,
+    ArabicLetter,
+    EuropeanNumber,
+    EuropeanSeparator,
+    EuropeanTerminator,
+    ArabicNumber,
+    CommonSeparator,
+    NonspacingMark,
+    BoundaryNeutral,
+    ParagraphSeparator,
+    SegmentSeparator,
+    WhiteSpace,
+    OtherNeutral,
+    LeftToRightEmbedding,
+    LeftToRightOverride,
+    RightToLeftEmbedding,
+    RightToLeftOverride,
+    PopDirectionalFormat,
+    LeftToRightIsolate,
+    RightToLeftIsolate,
+    FirstStrongIsolate,
+    PopDirectionalIsolate,
+}
+
+impl BidiClass {
+    /// All the bidi classes, in the order of UAX #9.
+    pub const ALL: [Self; 23] = [
+        Self::LeftToRight,
+        Self::RightToLeft,
+        Self::ArabicLetter,
+        Self::EuropeanNumber,
+        Self::EuropeanSeparator,
+        Self::EuropeanTerminator,
+        Self::ArabicNumber,
+        Self::CommonSeparator,
+        Self::NonspacingMark,
+        Self::BoundaryNeutral,
+        Self::ParagraphSeparator,
+        Self::SegmentSeparator,
+        Self::WhiteSpace,
+        Self::OtherNeutral,
+        Self::LeftToRightEmbedding,
+        Self::LeftToRightOverride,
+        Self::RightToLeftEmbedding,
+        Self::RightToLeftOverride,
+        Self::PopDirectionalFormat,
+        Self::LeftToRightIsolate,
+        Self::RightToLeftIsolate,
+        Self::FirstStrongIsolate,
+        Self::PopDirectionalIsolate,
+    ];
+
+    /// Find a bidi class by its abbreviation (e.g. `AL`).
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.abbr() == abbr)
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::LeftToRight => "L",
+            Self::RightToLeft => "R",
+            Self::ArabicLetter => "AL",
+            Self::EuropeanNumber => "EN",
+            Self::EuropeanSeparator => "ES",
+            Self::EuropeanTerminator => "ET",
+            Self::ArabicNumber => "AN",
+            Self::CommonSeparator => "CS",
+            Self::NonspacingMark => "NSM",
+            Self::BoundaryNeutral => "BN",
+            Self::ParagraphSeparator => "B",
+            Self::SegmentSeparator => "S",
+            Self::WhiteSpace => "WS",
+            Self::OtherNeutral => "ON",
+            Self::LeftToRightEmbedding => "LRE",
+            Self::LeftToRightOverride => "LRO",
+            Self::RightToLeftEmbedding => "RLE",
+            Self::RightToLeftOverride => "RLO",
+            Self::PopDirectionalFormat => "PDF",
+            Self::LeftToRightIsolate => "LRI",
+            Self::RightToLeftIsolate => "RLI",
+            Self::FirstStrongIsolate => "FSI",
+            Self::PopDirectionalIsolate => "PDI",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::LeftToRight => "Left to Right",
+            Self::RightToLeft => "Right to Left",
+            Self::ArabicLetter => "Arabic Letter",
+            Self::EuropeanNumber => "European Number",
+            Self::EuropeanSeparator => "European Separator",
+            Self::EuropeanTerminator => "European Terminator",
+            Self::ArabicNumber => "Arabic Number",
+            Self::CommonSeparator => "Common Separator",
+            Self::NonspacingMark => "Nonspacing Mark",
+            Self::BoundaryNeutral => "Boundary Neutral",
+            Self::ParagraphSeparator => "Paragraph Separator",
+            Self::SegmentSeparator => "Segment Separator",
+            Self::WhiteSpace => "White Space",
+            Self::OtherNeutral => "Other Neutral",
+            Self::LeftToRightEmbedding => "Left to Right Embedding",
+            Self::LeftToRightOverride => "Left to Right Override",
+            Self::RightToLeftEmbedding => "Right to Left Embedding",
+            Self::RightToLeftOverride => "Right to Left Override",
+            Self::PopDirectionalFormat => "Pop Directional Format",
+            Self::LeftToRightIsolate => "Left to Right Isolate",
+            Self::RightToLeftIsolate => "Right to Left Isolate",
+            Self::FirstStrongIsolate => "First Strong Isolate",
+            Self::PopDirectionalIsolate => "Pop Directional Isolate",
+        }
+    }
+
+    /// Whether the class is strong, i.e. it sets the direction of the text around it.
+    pub fn is_strong(self) -> bool {
+        matches!(
+            self,
+            Self::LeftToRight | Self::RightToLeft | Self::ArabicLetter
+        )
+    }
+}
+
+/// Value of the Bidi_Paired_Bracket_Type property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairedBracketType {
+    Open,
+    Close,
+    None,
+}
+
+impl PairedBracketType {
+    /// Parse the abbreviation used in the Unicode Character Database.
+    fn from_abbr(abbr: &str) -> Option<Self> {
+        match abbr {
+            "o" => Some(Self::Open),
+            "c" => Some(Self::Close),
+            "n" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Close => "Close",
+            Self::None => "None",
+        }
+    }
+}
+
+/// Get the bidi class of a codepoint.
+pub fn bidi_class(codepoint: u32) -> BidiClass {
+    lookup(&BIDI_CLASS, codepoint)
+        .and_then(|abbr| BidiClass::from_abbr(abbr))
+        .unwrap_or(BidiClass::LeftToRight)
+}
+
+/// Check whether a codepoint is mirrored in right-to-left text (the `Bidi_Mirrored` property).
+pub fn is_bidi_mirrored(codepoint: u32) -> bool {
+    has_property(codepoint, BinaryProperty::BidiMirrored)
+}
+
+/// Get the character whose glyph is the mirror image of the glyph of a codepoint
+/// (`Bidi_Mirroring_Glyph`), if there is one.
+///
+/// Some mirrored characters, such as `∛`, have no such counterpart, and must be mirrored by the
+/// font itself.
+pub fn bidi_mirroring_glyph(codepoint: u32) -> Option<u32> {
+    lookup_codepoint(&BIDI_MIRRORING_GLYPH, codepoint).copied()
+}
+
+/// Get the paired bracket type of a codepoint (`Bidi_Paired_Bracket_Type`).
+pub fn paired_bracket_type(codepoint: u32) -> PairedBracketType {
+    lookup(&BIDI_PAIRED_BRACKET_TYPE, codepoint)
+        .and_then(|abbr| PairedBracketType::from_abbr(abbr))
+        .unwrap_or(PairedBracketType::None)
+}
+
+/// Get the bracket that pairs with a codepoint (`Bidi_Paired_Bracket`), if it is a bracket.
+pub fn paired_bracket(codepoint: u32) -> Option<u32> {
+    lookup_codepoint(&BIDI_PAIRED_BRACKET, codepoint).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bidi_class() {
+        assert_eq!(bidi_class(0x41), BidiClass::LeftToRight);
+        assert_eq!(bidi_class(0x5D0), BidiClass::RightToLeft);
+        assert_eq!(bidi_class(0x627), BidiClass::ArabicLetter);
+        assert_eq!(bidi_class(0x31), BidiClass::EuropeanNumber);
+        assert_eq!(bidi_class(0x661), BidiClass::ArabicNumber);
+        assert_eq!(bidi_class(0x301), BidiClass::NonspacingMark);
+        assert_eq!(bidi_class(0x20), BidiClass::WhiteSpace);
+        assert_eq!(bidi_class(0x2067), BidiClass::RightToLeftIsolate);
+        assert_eq!(bidi_class(0x202E), BidiClass::RightToLeftOverride);
+    }
+
+    #[test]
+    fn test_bidi_class_names() {
+        for class in BidiClass::ALL {
+            assert_eq!(BidiClass::from_abbr(class.abbr()), Some(class));
+        }
+        assert_eq!(BidiClass::ArabicLetter.name(), "Arabic Letter");
+        assert!(BidiClass::RightToLeft.is_strong());
+        assert!(!BidiClass::EuropeanNumber.is_strong());
+    }
+
+    #[test]
+    fn test_mirroring() {
+        assert!(is_bidi_mirrored(0x28));
+        assert!(!is_bidi_mirrored(0x41));
+        assert_eq!(bidi_mirroring_glyph(0x28), Some(0x29));
+        assert_eq!(bidi_mirroring_glyph(0x2264), Some(0x2265));
+        assert_eq!(bidi_mirroring_glyph(0x41), None);
+        assert!(is_bidi_mirrored(0x221B)); // CUBE ROOT
+        assert_eq!(bidi_mirroring_glyph(0x221B), None);
+    }
+
+    #[test]
+    fn test_paired_brackets() {
+        assert_eq!(paired_bracket_type(0x28), PairedBracketType::Open);
+        assert_eq!(paired_bracket_type(0x5D), PairedBracketType::Close);
+        assert_eq!(paired_bracket_type(0x3C), PairedBracketType::None);
+        assert_eq!(paired_bracket(0x28), Some(0x29));
+        assert_eq!(paired_bracket(0x5D), Some(0x5B));
+        assert_eq!(paired_bracket(0x3C), None);
+    }
+}
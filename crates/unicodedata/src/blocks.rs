@@ -0,0 +1,112 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use std::ops::RangeInclusive;
+
+use crate::tables::{BLOCKS, loose_key};
+
+/// A block: a named range of codepoints, as listed in `Blocks.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    /// First codepoint of the block.
+    pub first: u32,
+    /// Last codepoint of the block.
+    pub last: u32,
+    /// Name of the block (e.g. `Latin-1 Supplement`).
+    pub name: &'static str,
+    /// Short alias of the block, as used by the `Block` property (e.g. `Latin_1_Sup`).
+    pub alias: &'static str,
+}
+
+impl Block {
+    fn from_entry((first, last, name, alias): &'static (u32, u32, String, String)) -> Self {
+        Self {
+            first: *first,
+            last: *last,
+            name,
+            alias,
+        }
+    }
+
+    /// Range of codepoints in the block.
+    pub fn codepoints(&self) -> RangeInclusive<u32> {
+        self.first..=self.last
+    }
+
+    /// Check whether the block contains a codepoint.
+    pub fn contains(&self, codepoint: u32) -> bool {
+        self.codepoints().contains(&codepoint)
+    }
+}
+
+/// Iterate over all blocks, in codepoint order.
+pub fn blocks() -> impl ExactSizeIterator<Item = Block> {
+    BLOCKS.iter().map(Block::from_entry)
+}
+
+/// Get the block a codepoint belongs to, if any.
+pub fn block_of(codepoint: u32) -> Option<Block> {
+    let idx = BLOCKS.partition_point(|&(_, last, _, _)| last < codepoint);
+    BLOCKS
+        .get(idx)
+        .map(Block::from_entry)
+        .filter(|block| block.first <= codepoint)
+}
+
+/// Find a block by its name or alias.
+///
+/// Names are matched loosely, so that `latin-1 supplement`, `Latin_1_Sup` and `isLatin1Supplement`
+/// all find the same block.
+pub fn block_by_name(name: &str) -> Option<Block> {
+    let key = loose_key(name);
+    blocks().find(|block| loose_key(block.name) == key || loose_key(block.alias) == key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_of() {
+        let block = block_of(0xC1).unwrap();
+        assert_eq!(block.name, "Latin-1 Supplement");
+        assert_eq!(block.alias, "Latin_1_Sup");
+        assert_eq!(block.codepoints(), 0x80..=0xFF);
+        assert_eq!(block_of(0x41).unwrap().name, "Basic Latin");
+        assert_eq!(block_of(0x10FFFF).unwrap().alias, "Sup_PUA_B");
+        assert_eq!(block_of(0x2FE0), None);
+    }
+
+    #[test]
+    fn test_block_by_name() {
+        let expected = block_of(0x80);
+        assert_eq!(block_by_name("Latin-1 Supplement"), expected);
+        assert_eq!(block_by_name("latin-1 supplement"), expected);
+        assert_eq!(block_by_name("LATIN_1_SUP"), expected);
+        assert_eq!(block_by_name("isLatin1Supplement"), expected);
+        assert_eq!(block_by_name("Latin-2 Supplement"), None);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let all: Vec<Block> = blocks().collect();
+        assert_eq!(all[0].name, "Basic Latin");
+        assert!(all.windows(2).all(|pair| pair[0].last < pair[1].first));
+        assert!(all.iter().all(|block| block.contains(block.first)));
+    }
+}
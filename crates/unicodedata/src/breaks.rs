@@ -0,0 +1,672 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{
+    GRAPHEME_CLUSTER_BREAK, INDIC_CONJUNCT_BREAK, LINE_BREAK, SENTENCE_BREAK, WORD_BREAK, lookup,
+};
+
+/// Value of the Line_Break property, used by the line breaking algorithm (UAX #14).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineBreak {
+    Ambiguous,
+    Aksara,
+    Alphabetic,
+    AksaraPrebase,
+    AksaraStart,
+    BreakBoth,
+    BreakAfter,
+    BreakBefore,
+    MandatoryBreak,
+    ContingentBreak,
+    ConditionalJapaneseStarter,
+    ClosePunctuation,
+    CombiningMark,
+    CloseParenthesis,
+    CarriageReturn,
+    EBase,
+    EModifier,
+    Exclamation,
+    Glue,
+    H2,
+    H3,
+    UnambiguousHyphen,
+    HebrewLetter,
+    Hyphen,
+    Ideographic,
+    Inseparable,
+    InfixNumeric,
+    JL,
+    JT,
+    JV,
+    LineFeed,
+    NextLine,
+    Nonstarter,
+    Numeric,
+    OpenPunctuation,
+    PostfixNumeric,
+    PrefixNumeric,
+    Quotation,
+    RegionalIndicator,
+    ComplexContext,
+    Surrogate,
+    Space,
+    BreakSymbols,
+    ViramaFinal,
+    Virama,
+    WordJoiner,
+    Unknown,
+    ZwSpace,
+    Zwj,
+}
+
+impl LineBreak {
+    /// All the values of the property.
+    pub const ALL: [Self; 49] = [
+        Self::Ambiguous,
+        Self::Aksara,
+        Self::Alphabetic,
+        Self::AksaraPrebase,
+        Self::AksaraStart,
+        Self::BreakBoth,
+        Self::BreakAfter,
+        Self::BreakBefore,
+        Self::MandatoryBreak,
+        Self::ContingentBreak,
+        Self::ConditionalJapaneseStarter,
+        Self::ClosePunctuation,
+        Self::CombiningMark,
+        Self::CloseParenthesis,
+        Self::CarriageReturn,
+        Self::EBase,
+        Self::EModifier,
+        Self::Exclamation,
+        Self::Glue,
+        Self::H2,
+        Self::H3,
+        Self::UnambiguousHyphen,
+        Self::HebrewLetter,
+        Self::Hyphen,
+        Self::Ideographic,
+        Self::Inseparable,
+        Self::InfixNumeric,
+        Self::JL,
+        Self::JT,
+        Self::JV,
+        Self::LineFeed,
+        Self::NextLine,
+        Self::Nonstarter,
+        Self::Numeric,
+        Self::OpenPunctuation,
+        Self::PostfixNumeric,
+        Self::PrefixNumeric,
+        Self::Quotation,
+        Self::RegionalIndicator,
+        Self::ComplexContext,
+        Self::Surrogate,
+        Self::Space,
+        Self::BreakSymbols,
+        Self::ViramaFinal,
+        Self::Virama,
+        Self::WordJoiner,
+        Self::Unknown,
+        Self::ZwSpace,
+        Self::Zwj,
+    ];
+
+    /// Find a value by its abbreviation, as used in the Unicode Character Database.
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|value| value.abbr() == abbr)
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Ambiguous => "AI",
+            Self::Aksara => "AK",
+            Self::Alphabetic => "AL",
+            Self::AksaraPrebase => "AP",
+            Self::AksaraStart => "AS",
+            Self::BreakBoth => "B2",
+            Self::BreakAfter => "BA",
+            Self::BreakBefore => "BB",
+            Self::MandatoryBreak => "BK",
+            Self::ContingentBreak => "CB",
+            Self::ConditionalJapaneseStarter => "CJ",
+            Self::ClosePunctuation => "CL",
+            Self::CombiningMark => "CM",
+            Self::CloseParenthesis => "CP",
+            Self::CarriageReturn => "CR",
+            Self::EBase => "EB",
+            Self::EModifier => "EM",
+            Self::Exclamation => "EX",
+            Self::Glue => "GL",
+            Self::H2 => "H2",
+            Self::H3 => "H3",
+            Self::UnambiguousHyphen => "HH",
+            Self::HebrewLetter => "HL",
+            Self::Hyphen => "HY",
+            Self::Ideographic => "ID",
+            Self::Inseparable => "IN",
+            Self::InfixNumeric => "IS",
+            Self::JL => "JL",
+            Self::JT => "JT",
+            Self::JV => "JV",
+            Self::LineFeed => "LF",
+            Self::NextLine => "NL",
+            Self::Nonstarter => "NS",
+            Self::Numeric => "NU",
+            Self::OpenPunctuation => "OP",
+            Self::PostfixNumeric => "PO",
+            Self::PrefixNumeric => "PR",
+            Self::Quotation => "QU",
+            Self::RegionalIndicator => "RI",
+            Self::ComplexContext => "SA",
+            Self::Surrogate => "SG",
+            Self::Space => "SP",
+            Self::BreakSymbols => "SY",
+            Self::ViramaFinal => "VF",
+            Self::Virama => "VI",
+            Self::WordJoiner => "WJ",
+            Self::Unknown => "XX",
+            Self::ZwSpace => "ZW",
+            Self::Zwj => "ZWJ",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Ambiguous => "Ambiguous",
+            Self::Aksara => "Aksara",
+            Self::Alphabetic => "Alphabetic",
+            Self::AksaraPrebase => "Aksara Prebase",
+            Self::AksaraStart => "Aksara Start",
+            Self::BreakBoth => "Break Both",
+            Self::BreakAfter => "Break After",
+            Self::BreakBefore => "Break Before",
+            Self::MandatoryBreak => "Mandatory Break",
+            Self::ContingentBreak => "Contingent Break",
+            Self::ConditionalJapaneseStarter => "Conditional Japanese Starter",
+            Self::ClosePunctuation => "Close Punctuation",
+            Self::CombiningMark => "Combining Mark",
+            Self::CloseParenthesis => "Close Parenthesis",
+            Self::CarriageReturn => "Carriage Return",
+            Self::EBase => "E Base",
+            Self::EModifier => "E Modifier",
+            Self::Exclamation => "Exclamation",
+            Self::Glue => "Glue",
+            Self::H2 => "H2",
+            Self::H3 => "H3",
+            Self::UnambiguousHyphen => "Unambiguous Hyphen",
+            Self::HebrewLetter => "Hebrew Letter",
+            Self::Hyphen => "Hyphen",
+            Self::Ideographic => "Ideographic",
+            Self::Inseparable => "Inseparable",
+            Self::InfixNumeric => "Infix Numeric",
+            Self::JL => "JL",
+            Self::JT => "JT",
+            Self::JV => "JV",
+            Self::LineFeed => "Line Feed",
+            Self::NextLine => "Next Line",
+            Self::Nonstarter => "Nonstarter",
+            Self::Numeric => "Numeric",
+            Self::OpenPunctuation => "Open Punctuation",
+            Self::PostfixNumeric => "Postfix Numeric",
+            Self::PrefixNumeric => "Prefix Numeric",
+            Self::Quotation => "Quotation",
+            Self::RegionalIndicator => "Regional Indicator",
+            Self::ComplexContext => "Complex Context",
+            Self::Surrogate => "Surrogate",
+            Self::Space => "Space",
+            Self::BreakSymbols => "Break Symbols",
+            Self::ViramaFinal => "Virama Final",
+            Self::Virama => "Virama",
+            Self::WordJoiner => "Word Joiner",
+            Self::Unknown => "Unknown",
+            Self::ZwSpace => "ZWSpace",
+            Self::Zwj => "ZWJ",
+        }
+    }
+}
+
+/// Get the Line_Break property of a codepoint.
+pub fn line_break(codepoint: u32) -> LineBreak {
+    lookup(&LINE_BREAK, codepoint)
+        .and_then(|abbr| LineBreak::from_abbr(abbr))
+        .unwrap_or(LineBreak::Unknown)
+}
+
+/// Value of the Word_Break property, used to find word boundaries (UAX #29).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordBreak {
+    CR,
+    DoubleQuote,
+    EBase,
+    EBaseGaz,
+    EModifier,
+    ExtendNumLet,
+    Extend,
+    Format,
+    GlueAfterZwj,
+    HebrewLetter,
+    Katakana,
+    ALetter,
+    LF,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Newline,
+    Numeric,
+    RegionalIndicator,
+    SingleQuote,
+    WSegSpace,
+    Other,
+    Zwj,
+}
+
+impl WordBreak {
+    /// All the values of the property.
+    pub const ALL: [Self; 23] = [
+        Self::CR,
+        Self::DoubleQuote,
+        Self::EBase,
+        Self::EBaseGaz,
+        Self::EModifier,
+        Self::ExtendNumLet,
+        Self::Extend,
+        Self::Format,
+        Self::GlueAfterZwj,
+        Self::HebrewLetter,
+        Self::Katakana,
+        Self::ALetter,
+        Self::LF,
+        Self::MidNumLet,
+        Self::MidLetter,
+        Self::MidNum,
+        Self::Newline,
+        Self::Numeric,
+        Self::RegionalIndicator,
+        Self::SingleQuote,
+        Self::WSegSpace,
+        Self::Other,
+        Self::Zwj,
+    ];
+
+    /// Find a value by its abbreviation, as used in the Unicode Character Database.
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|value| value.abbr() == abbr)
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::CR => "CR",
+            Self::DoubleQuote => "DQ",
+            Self::EBase => "EB",
+            Self::EBaseGaz => "EBG",
+            Self::EModifier => "EM",
+            Self::ExtendNumLet => "EX",
+            Self::Extend => "Extend",
+            Self::Format => "FO",
+            Self::GlueAfterZwj => "GAZ",
+            Self::HebrewLetter => "HL",
+            Self::Katakana => "KA",
+            Self::ALetter => "LE",
+            Self::LF => "LF",
+            Self::MidNumLet => "MB",
+            Self::MidLetter => "ML",
+            Self::MidNum => "MN",
+            Self::Newline => "NL",
+            Self::Numeric => "NU",
+            Self::RegionalIndicator => "RI",
+            Self::SingleQuote => "SQ",
+            Self::WSegSpace => "WSegSpace",
+            Self::Other => "XX",
+            Self::Zwj => "ZWJ",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::CR => "CR",
+            Self::DoubleQuote => "Double Quote",
+            Self::EBase => "E Base",
+            Self::EBaseGaz => "E Base GAZ",
+            Self::EModifier => "E Modifier",
+            Self::ExtendNumLet => "ExtendNumLet",
+            Self::Extend => "Extend",
+            Self::Format => "Format",
+            Self::GlueAfterZwj => "Glue After Zwj",
+            Self::HebrewLetter => "Hebrew Letter",
+            Self::Katakana => "Katakana",
+            Self::ALetter => "ALetter",
+            Self::LF => "LF",
+            Self::MidNumLet => "MidNumLet",
+            Self::MidLetter => "MidLetter",
+            Self::MidNum => "MidNum",
+            Self::Newline => "Newline",
+            Self::Numeric => "Numeric",
+            Self::RegionalIndicator => "Regional Indicator",
+            Self::SingleQuote => "Single Quote",
+            Self::WSegSpace => "WSegSpace",
+            Self::Other => "Other",
+            Self::Zwj => "ZWJ",
+        }
+    }
+}
+
+/// Get the Word_Break property of a codepoint.
+pub fn word_break(codepoint: u32) -> WordBreak {
+    lookup(&WORD_BREAK, codepoint)
+        .and_then(|abbr| WordBreak::from_abbr(abbr))
+        .unwrap_or(WordBreak::Other)
+}
+
+/// Value of the Sentence_Break property, used to find sentence boundaries (UAX #29).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SentenceBreak {
+    ATerm,
+    Close,
+    CR,
+    Extend,
+    Format,
+    OLetter,
+    LF,
+    Lower,
+    Numeric,
+    SContinue,
+    Sep,
+    Sp,
+    STerm,
+    Upper,
+    Other,
+}
+
+impl SentenceBreak {
+    /// All the values of the property.
+    pub const ALL: [Self; 15] = [
+        Self::ATerm,
+        Self::Close,
+        Self::CR,
+        Self::Extend,
+        Self::Format,
+        Self::OLetter,
+        Self::LF,
+        Self::Lower,
+        Self::Numeric,
+        Self::SContinue,
+        Self::Sep,
+        Self::Sp,
+        Self::STerm,
+        Self::Upper,
+        Self::Other,
+    ];
+
+    /// Find a value by its abbreviation, as used in the Unicode Character Database.
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|value| value.abbr() == abbr)
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::ATerm => "AT",
+            Self::Close => "CL",
+            Self::CR => "CR",
+            Self::Extend => "EX",
+            Self::Format => "FO",
+            Self::OLetter => "LE",
+            Self::LF => "LF",
+            Self::Lower => "LO",
+            Self::Numeric => "NU",
+            Self::SContinue => "SC",
+            Self::Sep => "SE",
+            Self::Sp => "SP",
+            Self::STerm => "ST",
+            Self::Upper => "UP",
+            Self::Other => "XX",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ATerm => "ATerm",
+            Self::Close => "Close",
+            Self::CR => "CR",
+            Self::Extend => "Extend",
+            Self::Format => "Format",
+            Self::OLetter => "OLetter",
+            Self::LF => "LF",
+            Self::Lower => "Lower",
+            Self::Numeric => "Numeric",
+            Self::SContinue => "SContinue",
+            Self::Sep => "Sep",
+            Self::Sp => "Sp",
+            Self::STerm => "STerm",
+            Self::Upper => "Upper",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// Get the Sentence_Break property of a codepoint.
+pub fn sentence_break(codepoint: u32) -> SentenceBreak {
+    lookup(&SENTENCE_BREAK, codepoint)
+        .and_then(|abbr| SentenceBreak::from_abbr(abbr))
+        .unwrap_or(SentenceBreak::Other)
+}
+
+/// Value of the Grapheme_Cluster_Break property, used to find grapheme cluster boundaries
+/// (UAX #29).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphemeClusterBreak {
+    Control,
+    CR,
+    EBase,
+    EBaseGaz,
+    EModifier,
+    Extend,
+    GlueAfterZwj,
+    L,
+    LF,
+    LV,
+    LVT,
+    Prepend,
+    RegionalIndicator,
+    SpacingMark,
+    T,
+    V,
+    Other,
+    Zwj,
+}
+
+impl GraphemeClusterBreak {
+    /// All the values of the property.
+    pub const ALL: [Self; 18] = [
+        Self::Control,
+        Self::CR,
+        Self::EBase,
+        Self::EBaseGaz,
+        Self::EModifier,
+        Self::Extend,
+        Self::GlueAfterZwj,
+        Self::L,
+        Self::LF,
+        Self::LV,
+        Self::LVT,
+        Self::Prepend,
+        Self::RegionalIndicator,
+        Self::SpacingMark,
+        Self::T,
+        Self::V,
+        Self::Other,
+        Self::Zwj,
+    ];
+
+    /// Find a value by its abbreviation, as used in the Unicode Character Database.
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|value| value.abbr() == abbr)
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Control => "CN",
+            Self::CR => "CR",
+            Self::EBase => "EB",
+            Self::EBaseGaz => "EBG",
+            Self::EModifier => "EM",
+            Self::Extend => "EX",
+            Self::GlueAfterZwj => "GAZ",
+            Self::L => "L",
+            Self::LF => "LF",
+            Self::LV => "LV",
+            Self::LVT => "LVT",
+            Self::Prepend => "PP",
+            Self::RegionalIndicator => "RI",
+            Self::SpacingMark => "SM",
+            Self::T => "T",
+            Self::V => "V",
+            Self::Other => "XX",
+            Self::Zwj => "ZWJ",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Control => "Control",
+            Self::CR => "CR",
+            Self::EBase => "E Base",
+            Self::EBaseGaz => "E Base GAZ",
+            Self::EModifier => "E Modifier",
+            Self::Extend => "Extend",
+            Self::GlueAfterZwj => "Glue After Zwj",
+            Self::L => "L",
+            Self::LF => "LF",
+            Self::LV => "LV",
+            Self::LVT => "LVT",
+            Self::Prepend => "Prepend",
+            Self::RegionalIndicator => "Regional Indicator",
+            Self::SpacingMark => "SpacingMark",
+            Self::T => "T",
+            Self::V => "V",
+            Self::Other => "Other",
+            Self::Zwj => "ZWJ",
+        }
+    }
+}
+
+/// Get the Grapheme_Cluster_Break property of a codepoint.
+pub fn grapheme_cluster_break(codepoint: u32) -> GraphemeClusterBreak {
+    lookup(&GRAPHEME_CLUSTER_BREAK, codepoint)
+        .and_then(|abbr| GraphemeClusterBreak::from_abbr(abbr))
+        .unwrap_or(GraphemeClusterBreak::Other)
+}
+
+/// Value of the Indic_Conjunct_Break property, used to keep Indic conjuncts in a single grapheme
+/// cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndicConjunctBreak {
+    Consonant,
+    Extend,
+    Linker,
+    None,
+}
+
+impl IndicConjunctBreak {
+    /// Parse the value used in the Unicode Character Database.
+    fn from_abbr(abbr: &str) -> Option<Self> {
+        match abbr {
+            "Consonant" => Some(Self::Consonant),
+            "Extend" => Some(Self::Extend),
+            "Linker" => Some(Self::Linker),
+            "None" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Get the Indic_Conjunct_Break property of a codepoint.
+pub fn indic_conjunct_break(codepoint: u32) -> IndicConjunctBreak {
+    lookup(&INDIC_CONJUNCT_BREAK, codepoint)
+        .and_then(|abbr| IndicConjunctBreak::from_abbr(abbr))
+        .unwrap_or(IndicConjunctBreak::None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abbreviations() {
+        for value in LineBreak::ALL {
+            assert_eq!(LineBreak::from_abbr(value.abbr()), Some(value));
+        }
+        for value in WordBreak::ALL {
+            assert_eq!(WordBreak::from_abbr(value.abbr()), Some(value));
+        }
+        for value in SentenceBreak::ALL {
+            assert_eq!(SentenceBreak::from_abbr(value.abbr()), Some(value));
+        }
+        for value in GraphemeClusterBreak::ALL {
+            assert_eq!(GraphemeClusterBreak::from_abbr(value.abbr()), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_line_break() {
+        assert_eq!(line_break(0x41), LineBreak::Alphabetic);
+        assert_eq!(line_break(0x20), LineBreak::Space);
+        assert_eq!(line_break(0x0A), LineBreak::LineFeed);
+        assert_eq!(line_break(0x28), LineBreak::OpenPunctuation);
+        assert_eq!(line_break(0x5186), LineBreak::Ideographic);
+        assert_eq!(line_break(0x2060), LineBreak::WordJoiner);
+        assert_eq!(line_break(0xE01), LineBreak::ComplexContext);
+    }
+
+    #[test]
+    fn test_word_and_sentence_break() {
+        assert_eq!(word_break(0x41), WordBreak::ALetter);
+        assert_eq!(word_break(0x27), WordBreak::SingleQuote);
+        assert_eq!(word_break(0x30A2), WordBreak::Katakana);
+        assert_eq!(word_break(0x5186), WordBreak::Other);
+        assert_eq!(sentence_break(0x41), SentenceBreak::Upper);
+        assert_eq!(sentence_break(0x61), SentenceBreak::Lower);
+        assert_eq!(sentence_break(0x2E), SentenceBreak::ATerm);
+        assert_eq!(sentence_break(0x21), SentenceBreak::STerm);
+    }
+
+    #[test]
+    fn test_grapheme_cluster_break() {
+        assert_eq!(grapheme_cluster_break(0x0D), GraphemeClusterBreak::CR);
+        assert_eq!(grapheme_cluster_break(0x301), GraphemeClusterBreak::Extend);
+        assert_eq!(grapheme_cluster_break(0x200D), GraphemeClusterBreak::Zwj);
+        assert_eq!(
+            grapheme_cluster_break(0x1F1E6),
+            GraphemeClusterBreak::RegionalIndicator
+        );
+        assert_eq!(grapheme_cluster_break(0x1100), GraphemeClusterBreak::L);
+        assert_eq!(grapheme_cluster_break(0xAC00), GraphemeClusterBreak::LV);
+        assert_eq!(grapheme_cluster_break(0xAC01), GraphemeClusterBreak::LVT);
+        assert_eq!(grapheme_cluster_break(0x41), GraphemeClusterBreak::Other);
+        assert_eq!(indic_conjunct_break(0x41), IndicConjunctBreak::None);
+    }
+}
@@ -0,0 +1,239 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::properties::{BinaryProperty, has_property};
+use crate::tables::{
+    CASE_FOLDING, LOWERCASE, SIMPLE_CASE_FOLDING, SIMPLE_LOWERCASE, SIMPLE_TITLECASE,
+    SIMPLE_UPPERCASE, TITLECASE, UPPERCASE, lookup_codepoint,
+};
+use crate::words::word_boundaries;
+
+/// GREEK CAPITAL LETTER SIGMA, which lowercases differently at the end of a word.
+const CAPITAL_SIGMA: char = '\u{3A3}';
+
+/// GREEK SMALL LETTER FINAL SIGMA.
+const FINAL_SIGMA: char = '\u{3C2}';
+
+/// Map a codepoint with a table of simple mappings, to itself if it isn’t in the table.
+fn simple_mapping(table: &[(u32, u32)], codepoint: u32) -> u32 {
+    lookup_codepoint(table, codepoint)
+        .copied()
+        .unwrap_or(codepoint)
+}
+
+/// Map a codepoint with a table of full mappings, to itself if it isn’t in the table.
+fn full_mapping(table: &[(u32, Vec<u32>)], codepoint: u32) -> Vec<u32> {
+    lookup_codepoint(table, codepoint)
+        .cloned()
+        .unwrap_or_else(|| vec![codepoint])
+}
+
+/// Get the simple uppercase mapping of a codepoint (`Simple_Uppercase_Mapping`).
+pub fn simple_uppercase(codepoint: u32) -> u32 {
+    simple_mapping(&SIMPLE_UPPERCASE, codepoint)
+}
+
+/// Get the simple lowercase mapping of a codepoint (`Simple_Lowercase_Mapping`).
+pub fn simple_lowercase(codepoint: u32) -> u32 {
+    simple_mapping(&SIMPLE_LOWERCASE, codepoint)
+}
+
+/// Get the simple titlecase mapping of a codepoint (`Simple_Titlecase_Mapping`).
+pub fn simple_titlecase(codepoint: u32) -> u32 {
+    simple_mapping(&SIMPLE_TITLECASE, codepoint)
+}
+
+/// Get the simple case folding of a codepoint (`Simple_Case_Folding`).
+pub fn simple_case_fold(codepoint: u32) -> u32 {
+    simple_mapping(&SIMPLE_CASE_FOLDING, codepoint)
+}
+
+/// Get the full uppercase mapping of a codepoint (`Uppercase_Mapping`), which may be longer than
+/// one codepoint (e.g. `ß` → `SS`).
+pub fn uppercase(codepoint: u32) -> Vec<u32> {
+    full_mapping(&UPPERCASE, codepoint)
+}
+
+/// Get the full lowercase mapping of a codepoint (`Lowercase_Mapping`).
+///
+/// This is the context-free mapping; [str_lowercase] also handles the final form of sigma.
+pub fn lowercase(codepoint: u32) -> Vec<u32> {
+    full_mapping(&LOWERCASE, codepoint)
+}
+
+/// Get the full titlecase mapping of a codepoint (`Titlecase_Mapping`).
+pub fn titlecase(codepoint: u32) -> Vec<u32> {
+    full_mapping(&TITLECASE, codepoint)
+}
+
+/// Get the full case folding of a codepoint (`Case_Folding`), used for caseless matching.
+pub fn case_fold(codepoint: u32) -> Vec<u32> {
+    full_mapping(&CASE_FOLDING, codepoint)
+}
+
+/// Check whether a codepoint is cased (the `Cased` property).
+pub fn is_cased(codepoint: u32) -> bool {
+    has_property(codepoint, BinaryProperty::Cased)
+}
+
+/// Check whether a codepoint is ignored when looking for cased letters around another one (the
+/// `Case_Ignorable` property).
+pub fn is_case_ignorable(codepoint: u32) -> bool {
+    has_property(codepoint, BinaryProperty::CaseIgnorable)
+}
+
+/// Apply a full mapping to every character of a string.
+fn map_str(text: &str, mapping: fn(u32) -> Vec<u32>) -> String {
+    text.chars()
+        .flat_map(|ch| mapping(u32::from(ch)))
+        .filter_map(char::from_u32)
+        .collect()
+}
+
+/// Convert a string to uppercase, with the full uppercase mappings.
+pub fn str_uppercase(text: &str) -> String {
+    map_str(text, uppercase)
+}
+
+/// Convert a string to lowercase, with the full lowercase mappings.
+///
+/// A capital sigma at the end of a word becomes a final sigma (`ς`), following the
+/// `Final_Sigma` condition of the Unicode Standard (section 3.13).
+pub fn str_lowercase(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for idx in 0..chars.len() {
+        push_lowercase(&mut out, &chars, idx);
+    }
+    out
+}
+
+/// Convert a string to titlecase: the first cased character of each word, as found by the word
+/// boundaries of UAX #29, gets its full titlecase mapping, and the cased characters after it are
+/// lowercased as with [str_lowercase]. Characters before the first cased one are kept as they are.
+pub fn str_titlecase(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut seen_cased = false;
+    for (idx, boundary) in word_boundaries(&chars).into_iter().enumerate() {
+        let codepoint = u32::from(chars[idx]);
+        if boundary {
+            seen_cased = false;
+        }
+        if seen_cased {
+            push_lowercase(&mut out, &chars, idx);
+        } else if is_cased(codepoint) {
+            seen_cased = true;
+            out.extend(titlecase(codepoint).into_iter().filter_map(char::from_u32));
+        } else {
+            out.push(chars[idx]);
+        }
+    }
+    out
+}
+
+/// Append the lowercase form of the character at `idx`, in the context of the others.
+fn push_lowercase(out: &mut String, chars: &[char], idx: usize) {
+    if chars[idx] == CAPITAL_SIGMA && is_final_sigma(chars, idx) {
+        out.push(FINAL_SIGMA);
+    } else {
+        out.extend(
+            lowercase(u32::from(chars[idx]))
+                .into_iter()
+                .filter_map(char::from_u32),
+        );
+    }
+}
+
+/// Check whether the character at `idx` is preceded by a cased letter and not followed by one,
+/// ignoring case-ignorable characters in between.
+fn is_final_sigma(chars: &[char], idx: usize) -> bool {
+    let is_relevant = |ch: &&char| !is_case_ignorable(u32::from(**ch));
+    let before = chars[..idx].iter().rev().find(is_relevant);
+    let after = chars[idx + 1..].iter().find(is_relevant);
+    before.is_some_and(|&ch| is_cased(u32::from(ch)))
+        && !after.is_some_and(|&ch| is_cased(u32::from(ch)))
+}
+
+/// Case fold a string, with the full case foldings, for caseless matching.
+pub fn str_case_fold(text: &str) -> String {
+    map_str(text, case_fold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_mappings() {
+        assert_eq!(simple_uppercase(0x61), 0x41);
+        assert_eq!(simple_lowercase(0x41), 0x61);
+        assert_eq!(simple_uppercase(0x41), 0x41);
+        assert_eq!(simple_uppercase(0xDF), 0xDF); // ß has no single uppercase
+        assert_eq!(simple_titlecase(0x1C6), 0x1C5); // ǆ → ǅ
+        assert_eq!(simple_uppercase(0x1C6), 0x1C4); // ǆ → Ǆ
+        assert_eq!(simple_case_fold(0x1E9E), 0xDF); // ẞ → ß
+    }
+
+    #[test]
+    fn test_full_mappings() {
+        assert_eq!(uppercase(0xDF), [0x53, 0x53]);
+        assert_eq!(titlecase(0xDF), [0x53, 0x73]);
+        assert_eq!(lowercase(0x130), [0x69, 0x307]);
+        assert_eq!(case_fold(0x1E9E), [0x73, 0x73]);
+        assert_eq!(uppercase(0x41), [0x41]);
+        assert_eq!(case_fold(0x5186), [0x5186]);
+    }
+
+    #[test]
+    fn test_str_mappings() {
+        assert_eq!(str_uppercase("straße"), "STRASSE");
+        assert_eq!(str_lowercase("ÁRVÍZTŰRŐ"), "árvíztűrő");
+        assert_eq!(str_case_fold("Straße"), str_case_fold("STRASSE"));
+        assert_eq!(str_uppercase("ﬃ円"), "FFI円");
+    }
+
+    #[test]
+    fn test_str_titlecase() {
+        assert_eq!(str_titlecase("hello WORLD"), "Hello World");
+        assert_eq!(str_titlecase("straße ßig"), "Straße Ssig");
+        assert_eq!(str_titlecase("ǆungla ǄUNGLA"), "ǅungla ǅungla");
+        assert_eq!(str_titlecase("ŉ ŉa"), "ʼN ʼNa");
+        assert_eq!(str_titlecase("can’t (stop) x86_64"), "Can’t (Stop) X86_64");
+        assert_eq!(str_titlecase("ΟΔΥΣΣΕΥΣ ΣΑ"), "Οδυσσευς Σα");
+        assert_eq!(str_titlecase(""), "");
+    }
+
+    #[test]
+    fn test_final_sigma() {
+        assert_eq!(str_lowercase("ΟΔΥΣΣΕΥΣ"), "οδυσσευς");
+        assert_eq!(str_lowercase("ΣΑ"), "σα");
+        assert_eq!(str_lowercase("Σ"), "σ");
+        assert_eq!(str_lowercase("ΑΣ."), "ας.");
+        assert_eq!(str_lowercase("ΑΣ'Α"), "ασ'α"); // the apostrophe is case-ignorable
+    }
+
+    #[test]
+    fn test_properties() {
+        assert!(is_cased(0x41));
+        assert!(!is_cased(0x5186));
+        assert!(is_case_ignorable(0x27));
+        assert!(is_case_ignorable(0x301));
+        assert!(!is_case_ignorable(0x41));
+    }
+}
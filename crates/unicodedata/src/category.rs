@@ -0,0 +1,307 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{general_category_abbr, loose_key};
+
+/// Value of the General_Category property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Control,
+    Format,
+    Surrogate,
+    PrivateUse,
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// All general categories, in the order they are listed in UAX #44.
+    pub const ALL: [Self; 30] = [
+        Self::UppercaseLetter,
+        Self::LowercaseLetter,
+        Self::TitlecaseLetter,
+        Self::ModifierLetter,
+        Self::OtherLetter,
+        Self::NonspacingMark,
+        Self::SpacingMark,
+        Self::EnclosingMark,
+        Self::DecimalNumber,
+        Self::LetterNumber,
+        Self::OtherNumber,
+        Self::ConnectorPunctuation,
+        Self::DashPunctuation,
+        Self::OpenPunctuation,
+        Self::ClosePunctuation,
+        Self::InitialPunctuation,
+        Self::FinalPunctuation,
+        Self::OtherPunctuation,
+        Self::MathSymbol,
+        Self::CurrencySymbol,
+        Self::ModifierSymbol,
+        Self::OtherSymbol,
+        Self::SpaceSeparator,
+        Self::LineSeparator,
+        Self::ParagraphSeparator,
+        Self::Control,
+        Self::Format,
+        Self::Surrogate,
+        Self::PrivateUse,
+        Self::Unassigned,
+    ];
+
+    /// Parse the two-letter abbreviation used in the Unicode Character Database (e.g. `Lu`).
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|gc| gc.abbr() == abbr)
+    }
+
+    /// Find a general category by its abbreviation or its name, matched loosely (e.g. `Sm`,
+    /// `Math_Symbol` or `math symbol`).
+    pub fn by_name(name: &str) -> Option<Self> {
+        let key = loose_key(name);
+        Self::ALL
+            .into_iter()
+            .find(|gc| loose_key(gc.abbr()) == key || loose_key(gc.name()) == key)
+    }
+
+    /// Two-letter abbreviation of the category, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::UppercaseLetter => "Lu",
+            Self::LowercaseLetter => "Ll",
+            Self::TitlecaseLetter => "Lt",
+            Self::ModifierLetter => "Lm",
+            Self::OtherLetter => "Lo",
+            Self::NonspacingMark => "Mn",
+            Self::SpacingMark => "Mc",
+            Self::EnclosingMark => "Me",
+            Self::DecimalNumber => "Nd",
+            Self::LetterNumber => "Nl",
+            Self::OtherNumber => "No",
+            Self::ConnectorPunctuation => "Pc",
+            Self::DashPunctuation => "Pd",
+            Self::OpenPunctuation => "Ps",
+            Self::ClosePunctuation => "Pe",
+            Self::InitialPunctuation => "Pi",
+            Self::FinalPunctuation => "Pf",
+            Self::OtherPunctuation => "Po",
+            Self::MathSymbol => "Sm",
+            Self::CurrencySymbol => "Sc",
+            Self::ModifierSymbol => "Sk",
+            Self::OtherSymbol => "So",
+            Self::SpaceSeparator => "Zs",
+            Self::LineSeparator => "Zl",
+            Self::ParagraphSeparator => "Zp",
+            Self::Control => "Cc",
+            Self::Format => "Cf",
+            Self::Surrogate => "Cs",
+            Self::PrivateUse => "Co",
+            Self::Unassigned => "Cn",
+        }
+    }
+
+    /// Full name of the category (e.g. `Uppercase Letter`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::UppercaseLetter => "Uppercase Letter",
+            Self::LowercaseLetter => "Lowercase Letter",
+            Self::TitlecaseLetter => "Titlecase Letter",
+            Self::ModifierLetter => "Modifier Letter",
+            Self::OtherLetter => "Other Letter",
+            Self::NonspacingMark => "Nonspacing Mark",
+            Self::SpacingMark => "Spacing Mark",
+            Self::EnclosingMark => "Enclosing Mark",
+            Self::DecimalNumber => "Decimal Number",
+            Self::LetterNumber => "Letter Number",
+            Self::OtherNumber => "Other Number",
+            Self::ConnectorPunctuation => "Connector Punctuation",
+            Self::DashPunctuation => "Dash Punctuation",
+            Self::OpenPunctuation => "Open Punctuation",
+            Self::ClosePunctuation => "Close Punctuation",
+            Self::InitialPunctuation => "Initial Punctuation",
+            Self::FinalPunctuation => "Final Punctuation",
+            Self::OtherPunctuation => "Other Punctuation",
+            Self::MathSymbol => "Math Symbol",
+            Self::CurrencySymbol => "Currency Symbol",
+            Self::ModifierSymbol => "Modifier Symbol",
+            Self::OtherSymbol => "Other Symbol",
+            Self::SpaceSeparator => "Space Separator",
+            Self::LineSeparator => "Line Separator",
+            Self::ParagraphSeparator => "Paragraph Separator",
+            Self::Control => "Control",
+            Self::Format => "Format",
+            Self::Surrogate => "Surrogate",
+            Self::PrivateUse => "Private Use",
+            Self::Unassigned => "Unassigned",
+        }
+    }
+
+    /// Major class the category belongs to, given by the first letter of its abbreviation.
+    pub fn major_class(self) -> MajorClass {
+        match self.abbr().as_bytes()[0] {
+            b'L' => MajorClass::Letter,
+            b'M' => MajorClass::Mark,
+            b'N' => MajorClass::Number,
+            b'P' => MajorClass::Punctuation,
+            b'S' => MajorClass::Symbol,
+            b'Z' => MajorClass::Separator,
+            _ => MajorClass::Other,
+        }
+    }
+}
+
+/// Major class of general categories, such as letters (`L`) or symbols (`S`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MajorClass {
+    Letter,
+    Mark,
+    Number,
+    Punctuation,
+    Symbol,
+    Separator,
+    Other,
+}
+
+impl MajorClass {
+    /// All major classes, in the order they are listed in UAX #44.
+    pub const ALL: [Self; 7] = [
+        Self::Letter,
+        Self::Mark,
+        Self::Number,
+        Self::Punctuation,
+        Self::Symbol,
+        Self::Separator,
+        Self::Other,
+    ];
+
+    /// Find a major class by its one-letter abbreviation or its name, matched loosely (e.g. `S`
+    /// or `symbol`).
+    pub fn by_name(name: &str) -> Option<Self> {
+        let key = loose_key(name);
+        Self::ALL
+            .into_iter()
+            .find(|class| loose_key(class.abbr()) == key || loose_key(class.name()) == key)
+    }
+
+    /// One-letter abbreviation of the class.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Letter => "L",
+            Self::Mark => "M",
+            Self::Number => "N",
+            Self::Punctuation => "P",
+            Self::Symbol => "S",
+            Self::Separator => "Z",
+            Self::Other => "C",
+        }
+    }
+
+    /// Name of the class.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Letter => "Letter",
+            Self::Mark => "Mark",
+            Self::Number => "Number",
+            Self::Punctuation => "Punctuation",
+            Self::Symbol => "Symbol",
+            Self::Separator => "Separator",
+            Self::Other => "Other",
+        }
+    }
+
+    /// General categories in the class.
+    pub fn categories(self) -> impl Iterator<Item = GeneralCategory> {
+        GeneralCategory::ALL
+            .into_iter()
+            .filter(move |gc| gc.major_class() == self)
+    }
+}
+
+/// Get the general category of a codepoint.
+pub fn general_category(codepoint: u32) -> GeneralCategory {
+    GeneralCategory::from_abbr(general_category_abbr(codepoint))
+        .unwrap_or(GeneralCategory::Unassigned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_general_category() {
+        assert_eq!(general_category(0x41), GeneralCategory::UppercaseLetter);
+        assert_eq!(general_category(0x2192), GeneralCategory::MathSymbol);
+        assert_eq!(general_category(0x0301), GeneralCategory::NonspacingMark);
+        assert_eq!(general_category(0xE000), GeneralCategory::PrivateUse);
+        assert_eq!(general_category(0x0378), GeneralCategory::Unassigned);
+    }
+
+    #[test]
+    fn test_names() {
+        for gc in GeneralCategory::ALL {
+            assert_eq!(GeneralCategory::from_abbr(gc.abbr()), Some(gc));
+            assert_eq!(GeneralCategory::by_name(gc.name()), Some(gc));
+        }
+        assert_eq!(GeneralCategory::UppercaseLetter.name(), "Uppercase Letter");
+        assert_eq!(
+            GeneralCategory::by_name("math_symbol"),
+            Some(GeneralCategory::MathSymbol)
+        );
+        assert_eq!(GeneralCategory::by_name("L"), None);
+        assert_eq!(GeneralCategory::from_abbr("Xx"), None);
+    }
+
+    #[test]
+    fn test_major_class() {
+        assert_eq!(
+            GeneralCategory::OtherLetter.major_class(),
+            MajorClass::Letter
+        );
+        assert_eq!(GeneralCategory::Unassigned.major_class(), MajorClass::Other);
+        assert_eq!(MajorClass::by_name("s"), Some(MajorClass::Symbol));
+        assert_eq!(
+            MajorClass::by_name("Separator"),
+            Some(MajorClass::Separator)
+        );
+        let total: usize = MajorClass::ALL.iter().map(|c| c.categories().count()).sum();
+        assert_eq!(total, GeneralCategory::ALL.len());
+    }
+}
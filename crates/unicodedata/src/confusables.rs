@@ -0,0 +1,117 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Confusable detection, as described in UTS #39 (Unicode Security Mechanisms).
+
+use crate::normalization::nfd;
+use crate::properties::{BinaryProperty, has_property};
+use crate::tables::{CONFUSABLES, lookup_codepoint};
+
+/// Compute the skeleton of a string, as described in section 4 of UTS #39. Two strings are
+/// confusable when they have the same skeleton.
+///
+/// The string is decomposed (NFD), its default ignorable characters are removed, every remaining
+/// character is replaced by the prototype it can be mistaken for, and the result is decomposed
+/// again. Skeletons are only meant to be compared, not shown to users.
+pub fn skeleton(text: &str) -> String {
+    let mut prototypes = String::new();
+    for ch in nfd(text).chars() {
+        let codepoint = u32::from(ch);
+        if has_property(codepoint, BinaryProperty::DefaultIgnorableCodePoint) {
+            continue;
+        }
+        match lookup_codepoint(&CONFUSABLES, codepoint) {
+            Some(prototype) => {
+                prototypes.extend(prototype.iter().filter_map(|&cp| char::from_u32(cp)))
+            }
+            None => prototypes.push(ch),
+        }
+    }
+    nfd(&prototypes)
+}
+
+/// Whether a character is listed in the confusables data as one that can be mistaken for another.
+pub(crate) fn has_prototype(codepoint: u32) -> bool {
+    lookup_codepoint(&CONFUSABLES, codepoint).is_some()
+}
+
+/// Whether two strings can be mistaken for each other, i.e. have the same skeleton (e.g.
+/// `paypal` and `раураl`, with Cyrillic letters).
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
+
+/// Find the characters that a character can be mistaken for on their own, that is, the other
+/// characters with the same skeleton, sorted by codepoint.
+pub fn confusables(codepoint: u32) -> Vec<u32> {
+    let Some(target) = char::from_u32(codepoint).map(|ch| skeleton(&ch.to_string())) else {
+        return Vec::new();
+    };
+    if target.is_empty() {
+        return Vec::new();
+    }
+    let mut target_chars = target.chars();
+    let prototype = match (target_chars.next(), target_chars.next()) {
+        (Some(ch), None) => Some(u32::from(ch)),
+        _ => None,
+    };
+    let mut found: Vec<u32> = CONFUSABLES
+        .iter()
+        .map(|&(source, _)| source)
+        .chain(prototype)
+        .filter(|&cp| cp != codepoint)
+        .filter(|&cp| char::from_u32(cp).is_some_and(|ch| skeleton(&ch.to_string()) == target))
+        .collect();
+    found.sort_unstable();
+    found.dedup();
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skeleton() {
+        assert_eq!(skeleton("раураl"), "paypal");
+        assert_eq!(skeleton("m"), "rn");
+        assert_eq!(skeleton("Ё"), "E\u{308}");
+        assert_eq!(skeleton("a\u{AD}b"), "ab");
+        assert_eq!(skeleton(""), "");
+    }
+
+    #[test]
+    fn test_is_confusable() {
+        assert!(is_confusable("paypal", "раураl"));
+        assert!(is_confusable("m", "rn"));
+        assert!(is_confusable("l1I", "lll"));
+        assert!(!is_confusable("paypal", "paypa1x"));
+        assert!(!is_confusable("a", "b"));
+    }
+
+    #[test]
+    fn test_confusables() {
+        assert!(confusables(0x61).contains(&0x430));
+        let cyrillic_a = confusables(0x430);
+        assert!(cyrillic_a.contains(&0x61));
+        assert!(!cyrillic_a.contains(&0x430));
+        assert!(cyrillic_a.is_sorted());
+        assert!(confusables(0x6C).contains(&0x31));
+        assert_eq!(confusables(0xAD), []);
+    }
+}
@@ -0,0 +1,304 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::hangul::{L_BASE, L_COUNT, N_COUNT, S_BASE, S_COUNT, T_BASE, T_COUNT, V_BASE, V_COUNT};
+use crate::tables::DECOMPOSITION;
+
+/// Type of a decomposition mapping (the `Decomposition_Type` property).
+///
+/// Every type but [DecompositionKind::Canonical] is a compatibility decomposition, whose tag
+/// tells how the character differs from its mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecompositionKind {
+    Canonical,
+    Compat,
+    Font,
+    NoBreak,
+    Initial,
+    Medial,
+    Final,
+    Isolated,
+    Circle,
+    Super,
+    Sub,
+    Vertical,
+    Wide,
+    Narrow,
+    Small,
+    Square,
+    Fraction,
+}
+
+impl DecompositionKind {
+    /// All decomposition types, canonical first.
+    const ALL: [Self; 17] = [
+        Self::Canonical,
+        Self::Compat,
+        Self::Font,
+        Self::NoBreak,
+        Self::Initial,
+        Self::Medial,
+        Self::Final,
+        Self::Isolated,
+        Self::Circle,
+        Self::Super,
+        Self::Sub,
+        Self::Vertical,
+        Self::Wide,
+        Self::Narrow,
+        Self::Small,
+        Self::Square,
+        Self::Fraction,
+    ];
+
+    /// Parse the abbreviation used in the XML representation of the UCD (e.g. `can`).
+    fn from_abbr(abbr: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.abbr() == abbr)
+    }
+
+    /// Abbreviation of the type, as used in the XML representation of the UCD.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Canonical => "can",
+            Self::Compat => "com",
+            Self::Font => "font",
+            Self::NoBreak => "nb",
+            Self::Initial => "init",
+            Self::Medial => "med",
+            Self::Final => "fin",
+            Self::Isolated => "iso",
+            Self::Circle => "enc",
+            Self::Super => "sup",
+            Self::Sub => "sub",
+            Self::Vertical => "vert",
+            Self::Wide => "wide",
+            Self::Narrow => "nar",
+            Self::Small => "sml",
+            Self::Square => "sqr",
+            Self::Fraction => "fra",
+        }
+    }
+
+    /// Name of the type (e.g. `Canonical` or `Superscript`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Canonical => "Canonical",
+            Self::Compat => "Compatibility",
+            Self::Font => "Font",
+            Self::NoBreak => "No-Break",
+            Self::Initial => "Initial",
+            Self::Medial => "Medial",
+            Self::Final => "Final",
+            Self::Isolated => "Isolated",
+            Self::Circle => "Circle",
+            Self::Super => "Superscript",
+            Self::Sub => "Subscript",
+            Self::Vertical => "Vertical",
+            Self::Wide => "Wide",
+            Self::Narrow => "Narrow",
+            Self::Small => "Small",
+            Self::Square => "Square",
+            Self::Fraction => "Fraction",
+        }
+    }
+
+    /// Tag of the type, as used in `UnicodeData.txt` (e.g. `<super>`); canonical decompositions
+    /// have none.
+    pub fn tag(self) -> Option<&'static str> {
+        match self {
+            Self::Canonical => None,
+            Self::Compat => Some("<compat>"),
+            Self::Font => Some("<font>"),
+            Self::NoBreak => Some("<noBreak>"),
+            Self::Initial => Some("<initial>"),
+            Self::Medial => Some("<medial>"),
+            Self::Final => Some("<final>"),
+            Self::Isolated => Some("<isolated>"),
+            Self::Circle => Some("<circle>"),
+            Self::Super => Some("<super>"),
+            Self::Sub => Some("<sub>"),
+            Self::Vertical => Some("<vertical>"),
+            Self::Wide => Some("<wide>"),
+            Self::Narrow => Some("<narrow>"),
+            Self::Small => Some("<small>"),
+            Self::Square => Some("<square>"),
+            Self::Fraction => Some("<fraction>"),
+        }
+    }
+
+    /// Whether this is a canonical decomposition.
+    pub fn is_canonical(self) -> bool {
+        self == Self::Canonical
+    }
+}
+
+/// Decomposition mapping of a character: the sequence of characters it is equivalent to, either
+/// canonically or only for compatibility.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decomposition {
+    pub kind: DecompositionKind,
+    /// Codepoints the character maps to. These may be further decomposable.
+    pub mapping: Vec<u32>,
+}
+
+/// Decompose a Hangul syllable into a leading consonant and a vowel, or into an LV syllable and
+/// a trailing consonant, as described in section 3.12 of the Unicode Standard.
+fn hangul_decomposition(codepoint: u32) -> Option<Vec<u32>> {
+    let index = codepoint.checked_sub(S_BASE).filter(|&i| i < S_COUNT)?;
+    let t_index = index % T_COUNT;
+    if t_index == 0 {
+        Some(vec![
+            L_BASE + index / N_COUNT,
+            V_BASE + (index % N_COUNT) / T_COUNT,
+        ])
+    } else {
+        Some(vec![codepoint - t_index, T_BASE + t_index])
+    }
+}
+
+/// Compose a leading consonant and a vowel into an LV syllable, or an LV syllable and a trailing
+/// consonant into an LVT syllable. This is the inverse of [hangul_decomposition].
+pub(crate) fn hangul_composition(first: u32, second: u32) -> Option<u32> {
+    let l_index = first.wrapping_sub(L_BASE);
+    let v_index = second.wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        return Some(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT);
+    }
+    let s_index = first.wrapping_sub(S_BASE);
+    let t_index = second.wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index.is_multiple_of(T_COUNT) && (1..T_COUNT).contains(&t_index) {
+        return Some(first + t_index);
+    }
+    None
+}
+
+/// Get the decomposition mapping of a codepoint, if it has one.
+///
+/// This is only the first level of the decomposition; see [canonical_decomposition] and
+/// [compatibility_decomposition] for the full expansion.
+pub fn decomposition(codepoint: u32) -> Option<Decomposition> {
+    if let Some(mapping) = hangul_decomposition(codepoint) {
+        return Some(Decomposition {
+            kind: DecompositionKind::Canonical,
+            mapping,
+        });
+    }
+    let idx = DECOMPOSITION
+        .binary_search_by_key(&codepoint, |&(cp, _, _)| cp)
+        .ok()?;
+    let (_, kind, mapping) = &DECOMPOSITION[idx];
+    Some(Decomposition {
+        kind: DecompositionKind::from_abbr(kind)?,
+        mapping: mapping.clone(),
+    })
+}
+
+/// Recursively apply the decomposition mappings to a codepoint, appending the result to `out`.
+fn expand(codepoint: u32, compatibility: bool, out: &mut Vec<u32>) {
+    match decomposition(codepoint) {
+        Some(d) if compatibility || d.kind.is_canonical() => {
+            for cp in d.mapping {
+                expand(cp, compatibility, out);
+            }
+        }
+        _ => out.push(codepoint),
+    }
+}
+
+/// Fully decompose a codepoint using only canonical mappings, as done by NFD.
+///
+/// Codepoints without a canonical decomposition decompose to themselves. The result isn’t
+/// reordered by combining class.
+pub fn canonical_decomposition(codepoint: u32) -> Vec<u32> {
+    let mut out = Vec::new();
+    expand(codepoint, false, &mut out);
+    out
+}
+
+/// Fully decompose a codepoint using both canonical and compatibility mappings, as done by NFKD.
+///
+/// Codepoints without a decomposition decompose to themselves. The result isn’t reordered by
+/// combining class.
+pub fn compatibility_decomposition(codepoint: u32) -> Vec<u32> {
+    let mut out = Vec::new();
+    expand(codepoint, true, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decomposition() {
+        assert_eq!(
+            decomposition(0xC1),
+            Some(Decomposition {
+                kind: DecompositionKind::Canonical,
+                mapping: vec![0x41, 0x301],
+            })
+        );
+        let sup2 = decomposition(0xB2).unwrap(); // SUPERSCRIPT TWO
+        assert_eq!(sup2.kind, DecompositionKind::Super);
+        assert_eq!(sup2.kind.tag(), Some("<super>"));
+        assert_eq!(sup2.mapping, [0x32]);
+        assert_eq!(decomposition(0x41), None);
+    }
+
+    #[test]
+    fn test_hangul_decomposition() {
+        // HANGUL SYLLABLE GA = G + A
+        assert_eq!(decomposition(0xAC00).unwrap().mapping, [0x1100, 0x1161]);
+        // HANGUL SYLLABLE GAG = GA + final G
+        assert_eq!(decomposition(0xAC01).unwrap().mapping, [0xAC00, 0x11A8]);
+        assert_eq!(canonical_decomposition(0xAC01), [0x1100, 0x1161, 0x11A8]);
+        assert_eq!(decomposition(0xD7A4), None);
+    }
+
+    #[test]
+    fn test_hangul_composition() {
+        assert_eq!(hangul_composition(0x1100, 0x1161), Some(0xAC00));
+        assert_eq!(hangul_composition(0xAC00, 0x11A8), Some(0xAC01));
+        assert_eq!(hangul_composition(0xAC01, 0x11A8), None); // already LVT
+        assert_eq!(hangul_composition(0xAC00, 0x11A7), None); // not a trailing consonant
+        assert_eq!(hangul_composition(0x41, 0x301), None);
+    }
+
+    #[test]
+    fn test_full_decomposition() {
+        // LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE → â + ´ → a + ̂ + ´
+        assert_eq!(canonical_decomposition(0x1EA5), [0x61, 0x302, 0x301]);
+        // ANGSTROM SIGN → Å → A + ̊
+        assert_eq!(canonical_decomposition(0x212B), [0x41, 0x30A]);
+        // LATIN SMALL LIGATURE FFI is a compatibility decomposition only.
+        assert_eq!(canonical_decomposition(0xFB03), [0xFB03]);
+        assert_eq!(compatibility_decomposition(0xFB03), [0x66, 0x66, 0x69]);
+        // DIAERESIS → space + combining diaeresis
+        assert_eq!(compatibility_decomposition(0xA8), [0x20, 0x308]);
+        assert_eq!(compatibility_decomposition(0x41), [0x41]);
+    }
+
+    #[test]
+    fn test_kinds() {
+        for kind in DecompositionKind::ALL {
+            assert_eq!(DecompositionKind::from_abbr(kind.abbr()), Some(kind));
+        }
+        assert_eq!(DecompositionKind::Canonical.tag(), None);
+        assert_eq!(DecompositionKind::Circle.name(), "Circle");
+    }
+}
@@ -0,0 +1,215 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{EMOJI_SEQUENCES, UNQUALIFIED_EMOJI};
+
+/// VARIATION SELECTOR-16, which requests the emoji presentation of the preceding character.
+const EMOJI_PRESENTATION_SELECTOR: u32 = 0xFE0F;
+
+/// Type of an emoji sequence, as given in the emoji data files (UTS #51).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmojiSequenceKind {
+    /// A character followed by VS16, to be shown as emoji (e.g. `☺️`).
+    Basic,
+    /// A digit, `#` or `*`, followed by VS16 and a combining enclosing keycap (e.g. `#️⃣`).
+    Keycap,
+    /// A pair of regional indicators (e.g. `🇧🇷`).
+    Flag,
+    /// A black flag followed by tag characters (e.g. the flag of England).
+    Tag,
+    /// An emoji followed by a skin tone modifier (e.g. `👍🏽`).
+    Modifier,
+    /// Emoji joined by zero width joiners (e.g. `👩‍👩‍👧`).
+    Zwj,
+}
+
+impl EmojiSequenceKind {
+    /// Parse the type field of the emoji data files.
+    fn from_type_field(field: &str) -> Option<Self> {
+        match field {
+            "Basic_Emoji" => Some(Self::Basic),
+            "Emoji_Keycap_Sequence" => Some(Self::Keycap),
+            "RGI_Emoji_Flag_Sequence" => Some(Self::Flag),
+            "RGI_Emoji_Tag_Sequence" => Some(Self::Tag),
+            "RGI_Emoji_Modifier_Sequence" => Some(Self::Modifier),
+            "RGI_Emoji_ZWJ_Sequence" => Some(Self::Zwj),
+            _ => None,
+        }
+    }
+
+    /// Name of the type of sequence.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Basic => "Emoji Presentation Sequence",
+            Self::Keycap => "Emoji Keycap Sequence",
+            Self::Flag => "Emoji Flag Sequence",
+            Self::Tag => "Emoji Tag Sequence",
+            Self::Modifier => "Emoji Modifier Sequence",
+            Self::Zwj => "Emoji ZWJ Sequence",
+        }
+    }
+}
+
+/// An emoji sequence recommended for general interchange (RGI), with its CLDR short name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmojiSequence {
+    pub codepoints: &'static [u32],
+    pub kind: EmojiSequenceKind,
+    /// Short name of the sequence, e.g. `family: woman, woman, girl`.
+    pub name: &'static str,
+}
+
+impl EmojiSequence {
+    fn from_table(entry: &'static (Vec<u32>, String, String)) -> Option<Self> {
+        let (codepoints, kind, name) = entry;
+        Some(Self {
+            codepoints,
+            kind: EmojiSequenceKind::from_type_field(kind)?,
+            name,
+        })
+    }
+}
+
+/// Iterate over all the emoji sequences, sorted by codepoints.
+pub fn emoji_sequences() -> impl Iterator<Item = EmojiSequence> {
+    EMOJI_SEQUENCES.iter().filter_map(EmojiSequence::from_table)
+}
+
+/// Find the emoji sequence made of exactly the given codepoints.
+///
+/// Sequences lacking some of their emoji presentation selectors (VS16), as often typed by hand,
+/// are also recognised.
+pub fn emoji_sequence(codepoints: &[u32]) -> Option<EmojiSequence> {
+    if let Ok(idx) = EMOJI_SEQUENCES.binary_search_by(|(cps, _, _)| cps.as_slice().cmp(codepoints))
+    {
+        return EmojiSequence::from_table(&EMOJI_SEQUENCES[idx]);
+    }
+    let unqualified = without_selectors(codepoints);
+    let idx = UNQUALIFIED_EMOJI.partition_point(|(cps, _)| *cps < unqualified);
+    let (cps, seq_idx) = UNQUALIFIED_EMOJI.get(idx)?;
+    if *cps != unqualified {
+        return None;
+    }
+    EmojiSequence::from_table(&EMOJI_SEQUENCES[*seq_idx as usize])
+}
+
+/// Find the longest emoji sequence at the start of the given codepoints, e.g. to split text into
+/// emoji.
+pub fn longest_emoji_sequence(codepoints: &[u32]) -> Option<EmojiSequence> {
+    (2..=codepoints.len())
+        .rev()
+        .find_map(|len| emoji_sequence(&codepoints[..len]))
+}
+
+/// Remove the emoji presentation selectors from a sequence.
+fn without_selectors(codepoints: &[u32]) -> Vec<u32> {
+    codepoints
+        .iter()
+        .copied()
+        .filter(|&cp| cp != EMOJI_PRESENTATION_SELECTOR)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codepoints(text: &str) -> Vec<u32> {
+        text.chars().map(u32::from).collect()
+    }
+
+    #[test]
+    fn test_emoji_sequence() {
+        let family = emoji_sequence(&codepoints("👩\u{200D}👩\u{200D}👧")).unwrap();
+        assert_eq!(family.kind, EmojiSequenceKind::Zwj);
+        assert_eq!(family.name, "family: woman, woman, girl");
+        let thumbs = emoji_sequence(&[0x1F44D, 0x1F3FD]).unwrap();
+        assert_eq!(thumbs.kind, EmojiSequenceKind::Modifier);
+        assert_eq!(thumbs.name, "thumbs up: medium skin tone");
+        let flag = emoji_sequence(&codepoints("🇧🇷")).unwrap();
+        assert_eq!(
+            (flag.kind, flag.name),
+            (EmojiSequenceKind::Flag, "flag: Brazil")
+        );
+        let keycap = emoji_sequence(&[0x23, 0xFE0F, 0x20E3]).unwrap();
+        assert_eq!(
+            (keycap.kind, keycap.name),
+            (EmojiSequenceKind::Keycap, "keycap: #")
+        );
+        let england = [
+            0x1F3F4, 0xE0067, 0xE0062, 0xE0065, 0xE006E, 0xE0067, 0xE007F,
+        ];
+        assert_eq!(
+            emoji_sequence(&england).unwrap().kind,
+            EmojiSequenceKind::Tag
+        );
+        assert_eq!(
+            emoji_sequence(&[0x263A, 0xFE0F]).unwrap().kind,
+            EmojiSequenceKind::Basic
+        );
+        assert_eq!(emoji_sequence(&[0x41, 0x42]), None);
+        assert_eq!(emoji_sequence(&[0x1F44D]), None);
+    }
+
+    #[test]
+    fn test_missing_selectors() {
+        // WOMAN, ZWJ, HEAVY BLACK HEART without VS16, ZWJ, MAN
+        let couple = emoji_sequence(&[0x1F469, 0x200D, 0x2764, 0x200D, 0x1F468]).unwrap();
+        assert_eq!(couple.name, "couple with heart: woman, man");
+        assert_eq!(
+            couple.codepoints,
+            [0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468]
+        );
+    }
+
+    #[test]
+    fn test_unqualified_index() {
+        // Every sequence is found from its codepoints without selectors, unless another sequence
+        // has the same ones and comes first.
+        for seq in emoji_sequences() {
+            let found = emoji_sequence(&without_selectors(seq.codepoints)).unwrap();
+            assert_eq!(
+                without_selectors(found.codepoints),
+                without_selectors(seq.codepoints)
+            );
+            assert!(found.codepoints <= seq.codepoints);
+        }
+        // Clusters that are not emoji aren't found.
+        assert_eq!(emoji_sequence(&[0x65, 0x301]), None);
+        assert_eq!(emoji_sequence(&[0x2764, 0x200D]), None);
+    }
+
+    #[test]
+    fn test_longest_emoji_sequence() {
+        let cps = codepoints("👩\u{200D}👩\u{200D}👧\u{200D}👦x");
+        let seq = longest_emoji_sequence(&cps).unwrap();
+        assert_eq!(seq.name, "family: woman, woman, girl, boy");
+        assert_eq!(seq.codepoints.len(), 7);
+        assert_eq!(longest_emoji_sequence(&codepoints("abc")), None);
+    }
+
+    #[test]
+    fn test_emoji_sequences_sorted() {
+        let all: Vec<EmojiSequence> = emoji_sequences().collect();
+        assert_eq!(all.len(), EMOJI_SEQUENCES.len());
+        assert!(
+            all.windows(2)
+                .all(|pair| pair[0].codepoints < pair[1].codepoints)
+        );
+    }
+}
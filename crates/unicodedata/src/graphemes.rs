@@ -0,0 +1,216 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::breaks::{
+    GraphemeClusterBreak, IndicConjunctBreak, grapheme_cluster_break, indic_conjunct_break,
+};
+use crate::properties::{BinaryProperty, has_property};
+
+/// Progress through an emoji ZWJ sequence, for rule GB11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmojiState {
+    None,
+    /// After an extended pictographic character and any number of extenders.
+    Pictographic,
+    /// After the above, followed by a zero width joiner.
+    Joined,
+}
+
+/// Progress through an Indic conjunct, for rule GB9c.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConjunctState {
+    None,
+    /// After a consonant and any number of extenders.
+    Consonant,
+    /// After the above, with at least one linker (virama) among the extenders.
+    Linked,
+}
+
+/// What is known about the text before a possible boundary.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    prev: GraphemeClusterBreak,
+    /// Number of regional indicators immediately before the boundary.
+    regional_indicators: usize,
+    emoji: EmojiState,
+    conjunct: ConjunctState,
+}
+
+impl State {
+    fn new(codepoint: u32) -> Self {
+        let state = Self {
+            prev: GraphemeClusterBreak::Other,
+            regional_indicators: 0,
+            emoji: EmojiState::None,
+            conjunct: ConjunctState::None,
+        };
+        state.advance(codepoint)
+    }
+
+    /// Update the state with the character after the boundary.
+    fn advance(self, codepoint: u32) -> Self {
+        use GraphemeClusterBreak as Gcb;
+        let gcb = grapheme_cluster_break(codepoint);
+        let emoji = if has_property(codepoint, BinaryProperty::ExtendedPictographic) {
+            EmojiState::Pictographic
+        } else {
+            match (self.emoji, gcb) {
+                (EmojiState::Pictographic, Gcb::Extend) => EmojiState::Pictographic,
+                (EmojiState::Pictographic, Gcb::Zwj) => EmojiState::Joined,
+                _ => EmojiState::None,
+            }
+        };
+        let conjunct = match (self.conjunct, indic_conjunct_break(codepoint)) {
+            (_, IndicConjunctBreak::Consonant) => ConjunctState::Consonant,
+            (ConjunctState::None, _) => ConjunctState::None,
+            (_, IndicConjunctBreak::Linker) => ConjunctState::Linked,
+            (conjunct, IndicConjunctBreak::Extend) => conjunct,
+            (_, IndicConjunctBreak::None) => ConjunctState::None,
+        };
+        Self {
+            prev: gcb,
+            regional_indicators: if gcb == Gcb::RegionalIndicator {
+                self.regional_indicators + 1
+            } else {
+                0
+            },
+            emoji,
+            conjunct,
+        }
+    }
+
+    /// Whether there is a grapheme cluster boundary before a codepoint, following the rules of
+    /// UAX #29.
+    fn is_boundary_before(&self, codepoint: u32) -> bool {
+        use GraphemeClusterBreak as Gcb;
+        let next = grapheme_cluster_break(codepoint);
+        match (self.prev, next) {
+            (Gcb::CR, Gcb::LF) => false,                             // GB3
+            (Gcb::Control | Gcb::CR | Gcb::LF, _) => true,           // GB4
+            (_, Gcb::Control | Gcb::CR | Gcb::LF) => true,           // GB5
+            (Gcb::L, Gcb::L | Gcb::V | Gcb::LV | Gcb::LVT) => false, // GB6
+            (Gcb::LV | Gcb::V, Gcb::V | Gcb::T) => false,            // GB7
+            (Gcb::LVT | Gcb::T, Gcb::T) => false,                    // GB8
+            (_, Gcb::Extend | Gcb::Zwj) => false,                    // GB9
+            (_, Gcb::SpacingMark) => false,                          // GB9a
+            (Gcb::Prepend, _) => false,                              // GB9b
+            _ if self.conjunct == ConjunctState::Linked
+                && indic_conjunct_break(codepoint) == IndicConjunctBreak::Consonant =>
+            {
+                false // GB9c
+            }
+            _ if self.emoji == EmojiState::Joined
+                && has_property(codepoint, BinaryProperty::ExtendedPictographic) =>
+            {
+                false // GB11
+            }
+            (Gcb::RegionalIndicator, Gcb::RegionalIndicator) => {
+                self.regional_indicators.is_multiple_of(2) // GB12, GB13
+            }
+            _ => true, // GB999
+        }
+    }
+}
+
+/// Iterator over the extended grapheme clusters of a string, returned by [graphemes].
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.text.char_indices();
+        let (_, first) = chars.next()?;
+        let mut state = State::new(u32::from(first));
+        let end = chars
+            .find(|&(_, ch)| {
+                let codepoint = u32::from(ch);
+                let boundary = state.is_boundary_before(codepoint);
+                state = state.advance(codepoint);
+                boundary
+            })
+            .map_or(self.text.len(), |(idx, _)| idx);
+        let (cluster, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+/// Split a string into extended grapheme clusters, i.e. user-perceived characters, as defined by
+/// UAX #29. For example, `e` followed by a combining acute accent is a single cluster, as is a
+/// flag made of two regional indicators or an emoji with a skin tone modifier.
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { text }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graphemes() {
+        let clusters: Vec<&str> = graphemes("e\u{301}👍🏽🇧🇷a\r\n").collect();
+        assert_eq!(clusters, ["e\u{301}", "👍🏽", "🇧🇷", "a", "\r\n"]);
+        assert_eq!(graphemes("").count(), 0);
+        assert_eq!(graphemes("👨\u{200D}👩\u{200D}👧").count(), 1);
+        assert_eq!(graphemes("🇦🇧🇨").collect::<Vec<_>>(), ["🇦🇧", "🇨"]);
+        assert_eq!(graphemes("\u{1100}\u{1161}\u{11A8}").count(), 1); // 각 as jamo
+    }
+
+    /// Check the segmentation against the conformance test of the UCD, whose lines look like
+    /// `÷ 0020 × 0308 ÷ 0020 ÷`, with `÷` marking boundaries and `×` the absence of one.
+    #[test]
+    fn test_grapheme_break_test() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../data/unicodedata/GraphemeBreakTest.txt"
+        );
+        let contents = std::fs::read_to_string(path).expect("GraphemeBreakTest.txt not found");
+        let mut count = 0;
+        'lines: for line in contents.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut expected = Vec::new();
+            let mut text = String::new();
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" if !text.is_empty() => expected.push(std::mem::take(&mut text)),
+                    "÷" | "×" => {}
+                    hex => {
+                        let codepoint = u32::from_str_radix(hex, 16).unwrap();
+                        // Surrogates can’t be part of a Rust string.
+                        let Some(ch) = char::from_u32(codepoint) else {
+                            continue 'lines;
+                        };
+                        text.push(ch);
+                    }
+                }
+            }
+            let input = expected.concat();
+            let actual: Vec<&str> = graphemes(&input).collect();
+            assert_eq!(actual, expected, "{line}");
+            count += 1;
+        }
+        assert!(count > 0);
+    }
+}
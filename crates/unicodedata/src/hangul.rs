@@ -0,0 +1,204 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{JAMO_SHORT_NAME, lookup};
+
+/// First Hangul syllable.
+pub(crate) const S_BASE: u32 = 0xAC00;
+/// First leading consonant jamo.
+pub(crate) const L_BASE: u32 = 0x1100;
+/// First vowel jamo.
+pub(crate) const V_BASE: u32 = 0x1161;
+/// One before the first trailing consonant jamo, as syllables without one have index 0.
+pub(crate) const T_BASE: u32 = 0x11A7;
+/// Number of leading consonant jamo.
+pub(crate) const L_COUNT: u32 = 19;
+/// Number of vowel jamo.
+pub(crate) const V_COUNT: u32 = 21;
+/// Number of trailing consonant jamo, plus one for syllables without one.
+pub(crate) const T_COUNT: u32 = 28;
+/// Number of syllables for each leading consonant.
+pub(crate) const N_COUNT: u32 = V_COUNT * T_COUNT;
+/// Number of Hangul syllables.
+pub(crate) const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Index of ㄹ among the leading consonants.
+const L_RIEUL: usize = 5;
+/// Index of ㅇ among the leading consonants, which is silent at the start of a syllable.
+const L_IEUNG: usize = 11;
+/// Index of ㄹ among the trailing consonants.
+const T_RIEUL: usize = 8;
+
+/// Revised Romanization of the leading consonants.
+const LEADING_ROMANIZATION: [&str; L_COUNT as usize] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+
+/// Revised Romanization of the vowels.
+const VOWEL_ROMANIZATION: [&str; V_COUNT as usize] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+
+/// Revised Romanization of the trailing consonants, at the end of a word or before a consonant.
+const TRAILING_ROMANIZATION: [&str; T_COUNT as usize] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// Revised Romanization of the trailing consonants before a syllable starting with a vowel, to
+/// which they are carried over (e.g. 한국어 → hangugeo).
+const TRAILING_BEFORE_VOWEL: [&str; T_COUNT as usize] = [
+    "", "g", "kk", "ks", "n", "nj", "n", "d", "r", "lg", "lm", "lb", "ls", "lt", "lp", "r", "m",
+    "b", "ps", "s", "ss", "ng", "j", "ch", "k", "t", "p", "",
+];
+
+/// A precomposed Hangul syllable, split into its jamo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HangulSyllable {
+    /// Leading consonant (choseong), from U+1100 to U+1112.
+    pub leading: u32,
+    /// Vowel (jungseong), from U+1161 to U+1175.
+    pub vowel: u32,
+    /// Trailing consonant (jongseong), from U+11A8 to U+11C2, if any.
+    pub trailing: Option<u32>,
+}
+
+impl HangulSyllable {
+    /// The jamo of the syllable, in order.
+    pub fn jamo(self) -> Vec<u32> {
+        [Some(self.leading), Some(self.vowel), self.trailing]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    fn leading_index(self) -> usize {
+        (self.leading - L_BASE) as usize
+    }
+
+    fn vowel_index(self) -> usize {
+        (self.vowel - V_BASE) as usize
+    }
+
+    fn trailing_index(self) -> usize {
+        self.trailing.map_or(0, |t| (t - T_BASE) as usize)
+    }
+}
+
+/// Split a precomposed Hangul syllable (U+AC00 to U+D7A3) into its jamo, as described in section
+/// 3.12 of the Unicode Standard.
+pub fn hangul_syllable(codepoint: u32) -> Option<HangulSyllable> {
+    let index = codepoint.checked_sub(S_BASE).filter(|&i| i < S_COUNT)?;
+    let t_index = index % T_COUNT;
+    Some(HangulSyllable {
+        leading: L_BASE + index / N_COUNT,
+        vowel: V_BASE + (index % N_COUNT) / T_COUNT,
+        trailing: (t_index != 0).then_some(T_BASE + t_index),
+    })
+}
+
+/// Get the short name of a jamo (the `Jamo_Short_Name` property), e.g. `G` for U+1100, as used in
+/// the names of Hangul syllables. The leading ㅇ (U+110B) is silent and has no short name.
+pub fn jamo_short_name(codepoint: u32) -> Option<&'static str> {
+    lookup(&JAMO_SHORT_NAME, codepoint).map(String::as_str)
+}
+
+/// Romanize the Hangul syllables of a string with the Revised Romanization of Korean, leaving
+/// any other characters as they are.
+///
+/// Trailing consonants are carried over to a following syllable that starts with a vowel, and
+/// ㄹㄹ becomes `ll`, but other sound changes, such as the assimilation in 설날 (`seollal`), are
+/// not applied.
+pub fn romanize(text: &str) -> String {
+    let syllables: Vec<Option<HangulSyllable>> = text
+        .chars()
+        .map(|ch| hangul_syllable(u32::from(ch)))
+        .collect();
+    let mut out = String::new();
+    for (idx, ch) in text.chars().enumerate() {
+        let Some(syllable) = syllables[idx] else {
+            out.push(ch);
+            continue;
+        };
+        let prev = idx.checked_sub(1).and_then(|prev| syllables[prev]);
+        let next = syllables.get(idx + 1).copied().flatten();
+        let leading = syllable.leading_index();
+        if leading == L_RIEUL && prev.is_some_and(|prev| prev.trailing_index() == T_RIEUL) {
+            out.push('l');
+        } else {
+            out.push_str(LEADING_ROMANIZATION[leading]);
+        }
+        out.push_str(VOWEL_ROMANIZATION[syllable.vowel_index()]);
+        let trailing = syllable.trailing_index();
+        if next.is_some_and(|next| next.leading_index() == L_IEUNG) {
+            out.push_str(TRAILING_BEFORE_VOWEL[trailing]);
+        } else {
+            out.push_str(TRAILING_ROMANIZATION[trailing]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hangul_syllable() {
+        assert_eq!(
+            hangul_syllable(0xAC00), // 가
+            Some(HangulSyllable {
+                leading: 0x1100,
+                vowel: 0x1161,
+                trailing: None
+            })
+        );
+        let han = hangul_syllable(0xD55C).unwrap(); // 한
+        assert_eq!(han.jamo(), [0x1112, 0x1161, 0x11AB]);
+        assert_eq!(
+            hangul_syllable(0xD7A3).unwrap().jamo(),
+            [0x1112, 0x1175, 0x11C2]
+        );
+        assert_eq!(hangul_syllable(0xD7A4), None);
+        assert_eq!(hangul_syllable(0x1100), None);
+    }
+
+    #[test]
+    fn test_jamo_short_name() {
+        assert_eq!(jamo_short_name(0x1100), Some("G"));
+        assert_eq!(jamo_short_name(0x110B), None);
+        assert_eq!(jamo_short_name(0x1161), Some("A"));
+        assert_eq!(jamo_short_name(0x11A8), Some("G"));
+        assert_eq!(jamo_short_name(0x11BC), Some("NG"));
+        assert_eq!(jamo_short_name(0x41), None);
+    }
+
+    #[test]
+    fn test_romanize() {
+        assert_eq!(romanize("한국어"), "hangugeo");
+        assert_eq!(romanize("서울"), "seoul");
+        assert_eq!(romanize("안녕하세요"), "annyeonghaseyo");
+        assert_eq!(romanize("김치"), "gimchi");
+        assert_eq!(romanize("빨리"), "ppalli");
+        assert_eq!(romanize("좋아"), "joa");
+        assert_eq!(romanize("부산 2002!"), "busan 2002!");
+        assert_eq!(romanize("각"), "gak");
+    }
+}
@@ -0,0 +1,87 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+mod age;
+mod bidi;
+mod blocks;
+mod breaks;
+mod case;
+mod category;
+mod confusables;
+mod decomposition;
+mod emoji;
+mod graphemes;
+mod hangul;
+mod names;
+mod normalization;
+mod numeric;
+mod planes;
+mod properties;
+mod scripts;
+mod security;
+mod tables;
+#[cfg(feature = "unihan")]
+mod unihan;
+mod width;
+mod words;
+
+pub use age::{ReleaseDate, UnicodeVersion, age, characters_added_in};
+pub use bidi::{
+    BidiClass, PairedBracketType, bidi_class, bidi_mirroring_glyph, is_bidi_mirrored,
+    paired_bracket, paired_bracket_type,
+};
+pub use blocks::{Block, block_by_name, block_of, blocks};
+pub use breaks::{
+    GraphemeClusterBreak, IndicConjunctBreak, LineBreak, SentenceBreak, WordBreak,
+    grapheme_cluster_break, indic_conjunct_break, line_break, sentence_break, word_break,
+};
+pub use case::{
+    case_fold, is_case_ignorable, is_cased, lowercase, simple_case_fold, simple_lowercase,
+    simple_titlecase, simple_uppercase, str_case_fold, str_lowercase, str_titlecase, str_uppercase,
+    titlecase, uppercase,
+};
+pub use category::{GeneralCategory, MajorClass, general_category};
+pub use confusables::{confusables, is_confusable, skeleton};
+pub use decomposition::{
+    Decomposition, DecompositionKind, canonical_decomposition, compatibility_decomposition,
+    decomposition,
+};
+pub use emoji::{
+    EmojiSequence, EmojiSequenceKind, emoji_sequence, emoji_sequences, longest_emoji_sequence,
+};
+pub use graphemes::{Graphemes, graphemes};
+pub use hangul::{HangulSyllable, hangul_syllable, jamo_short_name, romanize};
+pub use names::{display_name, is_assigned, name};
+pub use normalization::{
+    NormalizationForm, QuickCheck, canonical_combining_class, is_nfc, is_nfd, is_nfkc, is_nfkd,
+    is_normalized, nfc, nfd, nfkc, nfkd, normalize, quick_check,
+};
+pub use numeric::{NumericType, NumericValue, characters_with_value, numeric_type, numeric_value};
+pub use planes::Plane;
+pub use properties::{BinaryProperty, binary_properties, has_property};
+pub use scripts::{Script, characters_in_script, script, script_extensions};
+pub use security::{
+    CharacterFlag, FlaggedCharacter, IdentifierStatus, IdentifierType, RestrictionLevel, ScriptSet,
+    StringAnalysis, analyze_string, identifier_status, identifier_types, resolved_script_set,
+};
+#[cfg(feature = "unihan")]
+pub use unihan::{RadicalStroke, Unihan, characters_with_radical, kangxi_radical, unihan};
+pub use width::{AmbiguousWidth, EastAsianWidth, display_width, east_asian_width, str_width};
+
+/// Version of the included Unicode Character Database.
+pub const UNICODE_VERSION: &str = include_str!(env!("UNICODEDATA_VERSION_FILE"));
@@ -0,0 +1,94 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{NAMES, general_category_abbr, lookup};
+
+/// Get the name of a character (its `Name` property), if it has one.
+///
+/// Control characters, private-use characters, surrogates, noncharacters and unassigned
+/// codepoints have no name.
+pub fn name(codepoint: u32) -> Option<String> {
+    lookup(&NAMES, codepoint).map(|name| name.replace('#', &format!("{codepoint:04X}")))
+}
+
+/// Get the name of a character or, if it has none, its code point label (e.g. `<control-0009>`),
+/// as described in section 4.8 of the Unicode Standard.
+pub fn display_name(codepoint: u32) -> String {
+    if let Some(name) = name(codepoint) {
+        return name;
+    }
+    let kind = match general_category_abbr(codepoint) {
+        "Cc" => "control",
+        "Co" => "private-use",
+        "Cs" => "surrogate",
+        _ if is_noncharacter(codepoint) => "noncharacter",
+        _ => "reserved",
+    };
+    format!("<{kind}-{codepoint:04X}>")
+}
+
+/// Check whether a codepoint is assigned, i.e. its general category isn’t `Cn` (unassigned).
+pub fn is_assigned(codepoint: u32) -> bool {
+    general_category_abbr(codepoint) != "Cn"
+}
+
+/// Check whether a codepoint is one of the 66 noncharacters.
+pub(crate) fn is_noncharacter(codepoint: u32) -> bool {
+    (0xFDD0..=0xFDEF).contains(&codepoint) || codepoint & 0xFFFE == 0xFFFE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_assigned() {
+        assert!(is_assigned(0x41));
+        assert!(is_assigned(0xE000));
+        assert!(!is_assigned(0x0378));
+        assert!(!is_assigned(0xFFFF));
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(name(0x41).as_deref(), Some("LATIN CAPITAL LETTER A"));
+        assert_eq!(
+            name(0xC1).as_deref(),
+            Some("LATIN CAPITAL LETTER A WITH ACUTE")
+        );
+        assert_eq!(name(0x5186).as_deref(), Some("CJK UNIFIED IDEOGRAPH-5186"));
+        assert_eq!(
+            name(0x20000).as_deref(),
+            Some("CJK UNIFIED IDEOGRAPH-20000")
+        );
+        assert_eq!(name(0xAC00).as_deref(), Some("HANGUL SYLLABLE GA"));
+        assert_eq!(name(0x0000), None);
+        assert_eq!(name(0x0378), None);
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(display_name(0x20), "SPACE");
+        assert_eq!(display_name(0x09), "<control-0009>");
+        assert_eq!(display_name(0x0378), "<reserved-0378>");
+        assert_eq!(display_name(0xD800), "<surrogate-D800>");
+        assert_eq!(display_name(0xE000), "<private-use-E000>");
+        assert_eq!(display_name(0xFDD0), "<noncharacter-FDD0>");
+        assert_eq!(display_name(0x10FFFF), "<noncharacter-10FFFF>");
+    }
+}
@@ -0,0 +1,370 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::decomposition::{
+    canonical_decomposition, compatibility_decomposition, hangul_composition,
+};
+use crate::tables::{
+    COMBINING_CLASS, COMPOSITION, NFC_QUICK_CHECK, NFD_QUICK_CHECK, NFKC_QUICK_CHECK,
+    NFKD_QUICK_CHECK, lookup,
+};
+
+/// One of the four Unicode normalization forms (UAX #15).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// All normalization forms.
+    pub const ALL: [Self; 4] = [Self::Nfc, Self::Nfd, Self::Nfkc, Self::Nfkd];
+
+    /// Name of the form (e.g. `NFC`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Nfc => "NFC",
+            Self::Nfd => "NFD",
+            Self::Nfkc => "NFKC",
+            Self::Nfkd => "NFKD",
+        }
+    }
+
+    /// Whether the form uses compatibility decompositions.
+    fn is_compatibility(self) -> bool {
+        matches!(self, Self::Nfkc | Self::Nfkd)
+    }
+
+    /// Whether the form recomposes characters after decomposing them.
+    fn is_composed(self) -> bool {
+        matches!(self, Self::Nfc | Self::Nfkc)
+    }
+
+    /// Table of the quick check property of the form (e.g. `NFC_Quick_Check`).
+    fn quick_check_table(self) -> &'static [(u32, u32, String)] {
+        match self {
+            Self::Nfc => &NFC_QUICK_CHECK,
+            Self::Nfd => &NFD_QUICK_CHECK,
+            Self::Nfkc => &NFKC_QUICK_CHECK,
+            Self::Nfkd => &NFKD_QUICK_CHECK,
+        }
+    }
+}
+
+/// Result of a quick check for whether a string is normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuickCheck {
+    Yes,
+    No,
+    /// The string can only be told to be normalized or not by normalizing it.
+    Maybe,
+}
+
+/// Get the canonical combining class of a codepoint (its `ccc` property).
+pub fn canonical_combining_class(codepoint: u32) -> u8 {
+    lookup(&COMBINING_CLASS, codepoint).copied().unwrap_or(0)
+}
+
+/// Get the primary composite of two characters, if there is one.
+fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    if let Some(composite) = hangul_composition(first, second) {
+        return Some(composite);
+    }
+    let idx = COMPOSITION
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()?;
+    Some(COMPOSITION[idx].2)
+}
+
+/// Fully decompose a string, then put combining marks in canonical order.
+fn decompose(text: &str, compatibility: bool) -> Vec<u32> {
+    let mut out = Vec::with_capacity(text.len());
+    for ch in text.chars() {
+        if compatibility {
+            out.extend(compatibility_decomposition(u32::from(ch)));
+        } else {
+            out.extend(canonical_decomposition(u32::from(ch)));
+        }
+    }
+    reorder(&mut out);
+    out
+}
+
+/// Apply the canonical ordering algorithm: sort each run of non-starters by combining class,
+/// keeping the order of marks with the same class.
+fn reorder(codepoints: &mut [u32]) {
+    let mut start = 0;
+    while start < codepoints.len() {
+        if canonical_combining_class(codepoints[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = codepoints[start..]
+            .iter()
+            .position(|&cp| canonical_combining_class(cp) == 0)
+            .map_or(codepoints.len(), |len| start + len);
+        codepoints[start..end].sort_by_key(|&cp| canonical_combining_class(cp));
+        start = end;
+    }
+}
+
+/// Apply the canonical composition algorithm to a decomposed, reordered sequence.
+fn compose(codepoints: &[u32]) -> Vec<u32> {
+    let mut out: Vec<u32> = Vec::with_capacity(codepoints.len());
+    // Index of the last starter, and the class of the last character appended after it.
+    let mut starter: Option<usize> = None;
+    let mut last_class: Option<u8> = None;
+
+    for &cp in codepoints {
+        let class = canonical_combining_class(cp);
+        // A character is blocked from the starter by any character in between whose class is 0
+        // or not lower than its own.
+        let blocked = last_class.is_some_and(|last| last == 0 || last >= class);
+        if let Some(idx) = starter.filter(|_| !blocked)
+            && let Some(composite) = compose_pair(out[idx], cp)
+        {
+            out[idx] = composite;
+            continue;
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(cp);
+    }
+    out
+}
+
+/// Convert codepoints resulting from normalization back into a string.
+fn to_string(codepoints: &[u32]) -> String {
+    codepoints
+        .iter()
+        .filter_map(|&cp| char::from_u32(cp))
+        .collect()
+}
+
+/// Normalize a string to the given form.
+pub fn normalize(text: &str, form: NormalizationForm) -> String {
+    if quick_check(text, form) == QuickCheck::Yes {
+        return text.to_string();
+    }
+    let decomposed = decompose(text, form.is_compatibility());
+    if form.is_composed() {
+        to_string(&compose(&decomposed))
+    } else {
+        to_string(&decomposed)
+    }
+}
+
+/// Normalize a string to NFC.
+pub fn nfc(text: &str) -> String {
+    normalize(text, NormalizationForm::Nfc)
+}
+
+/// Normalize a string to NFD.
+pub fn nfd(text: &str) -> String {
+    normalize(text, NormalizationForm::Nfd)
+}
+
+/// Normalize a string to NFKC.
+pub fn nfkc(text: &str) -> String {
+    normalize(text, NormalizationForm::Nfkc)
+}
+
+/// Normalize a string to NFKD.
+pub fn nfkd(text: &str) -> String {
+    normalize(text, NormalizationForm::Nfkd)
+}
+
+/// Quickly check whether a string is normalized, using the quick check properties, as described
+/// in UAX #15. The answer may be [QuickCheck::Maybe].
+pub fn quick_check(text: &str, form: NormalizationForm) -> QuickCheck {
+    let table = form.quick_check_table();
+    let mut last_class = 0;
+    let mut result = QuickCheck::Yes;
+    for ch in text.chars() {
+        let cp = u32::from(ch);
+        let class = canonical_combining_class(cp);
+        if last_class > class && class != 0 {
+            return QuickCheck::No;
+        }
+        match lookup(table, cp).map(String::as_str) {
+            Some("N") => return QuickCheck::No,
+            Some("M") => result = QuickCheck::Maybe,
+            _ => {}
+        }
+        last_class = class;
+    }
+    result
+}
+
+/// Check whether a string is normalized in the given form.
+pub fn is_normalized(text: &str, form: NormalizationForm) -> bool {
+    match quick_check(text, form) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => normalize(text, form) == text,
+    }
+}
+
+/// Check whether a string is in NFC.
+pub fn is_nfc(text: &str) -> bool {
+    is_normalized(text, NormalizationForm::Nfc)
+}
+
+/// Check whether a string is in NFD.
+pub fn is_nfd(text: &str) -> bool {
+    is_normalized(text, NormalizationForm::Nfd)
+}
+
+/// Check whether a string is in NFKC.
+pub fn is_nfkc(text: &str) -> bool {
+    is_normalized(text, NormalizationForm::Nfkc)
+}
+
+/// Check whether a string is in NFKD.
+pub fn is_nfkd(text: &str) -> bool {
+    is_normalized(text, NormalizationForm::Nfkd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::fs;
+
+    /// Path of the conformance test file of UAX #15.
+    const NORMALIZATION_TEST: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../data/unicodedata/NormalizationTest.txt"
+    );
+
+    fn parse_field(field: &str) -> String {
+        field
+            .split_whitespace()
+            .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_canonical_combining_class() {
+        assert_eq!(canonical_combining_class(0x41), 0);
+        assert_eq!(canonical_combining_class(0x301), 230);
+        assert_eq!(canonical_combining_class(0x323), 220);
+        assert_eq!(canonical_combining_class(0x334), 1);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(nfd("Á"), "A\u{301}");
+        assert_eq!(nfc("A\u{301}"), "Á");
+        assert_eq!(nfc("\u{212B}"), "\u{C5}"); // ANGSTROM SIGN
+        assert_eq!(nfd("\u{1E0B}\u{323}"), "d\u{323}\u{307}");
+        assert_eq!(nfc("\u{1E0B}\u{323}"), "\u{1E0D}\u{307}");
+        assert_eq!(nfkc("ﬃ"), "ffi");
+        assert_eq!(nfkd("²"), "2");
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+        assert_eq!(nfd("\u{AC01}"), "\u{1100}\u{1161}\u{11A8}");
+    }
+
+    #[test]
+    fn test_quick_check() {
+        assert_eq!(quick_check("abc", NormalizationForm::Nfc), QuickCheck::Yes);
+        assert_eq!(quick_check("Á", NormalizationForm::Nfd), QuickCheck::No);
+        assert_eq!(
+            quick_check("A\u{301}", NormalizationForm::Nfc),
+            QuickCheck::Maybe
+        );
+        assert_eq!(
+            quick_check("a\u{301}\u{323}", NormalizationForm::Nfd),
+            QuickCheck::No
+        );
+        assert!(is_nfc("Á"));
+        assert!(!is_nfc("A\u{301}"));
+        assert!(is_nfd("A\u{301}"));
+        assert!(!is_nfkc("ﬃ"));
+        assert!(is_nfkd("ffi"));
+    }
+
+    /// Check the invariants listed in `NormalizationTest.txt` for each of its lines, and that
+    /// characters not listed in part 1 are left unchanged by all forms.
+    #[test]
+    fn test_normalization_conformance() {
+        let contents = fs::read_to_string(NORMALIZATION_TEST).unwrap();
+        let mut part = "";
+        let mut part1 = HashSet::new();
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap();
+            if let Some(name) = line.strip_prefix('@') {
+                part = name.trim();
+                continue;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let c: Vec<String> = line.split(';').take(5).map(parse_field).collect();
+            let [c1, c2, c3, c4, c5] = &c[..] else {
+                panic!("Invalid line: {line}");
+            };
+            if part == "Part1" {
+                part1.insert(c1.chars().next().unwrap());
+            }
+            for source in [c1, c2, c3] {
+                assert_eq!(&nfc(source), c2, "NFC({source:?}), line {line}");
+                assert_eq!(&nfd(source), c3, "NFD({source:?}), line {line}");
+            }
+            for source in [c4, c5] {
+                assert_eq!(&nfc(source), c4, "NFC({source:?}), line {line}");
+                assert_eq!(&nfd(source), c5, "NFD({source:?}), line {line}");
+            }
+            for source in [c1, c2, c3, c4, c5] {
+                assert_eq!(&nfkc(source), c4, "NFKC({source:?}), line {line}");
+                assert_eq!(&nfkd(source), c5, "NFKD({source:?}), line {line}");
+            }
+            assert_eq!(is_nfc(c1), c1 == c2, "is_nfc({c1:?}), line {line}");
+            assert_eq!(is_nfd(c1), c1 == c3, "is_nfd({c1:?}), line {line}");
+            assert_eq!(is_nfkc(c1), c1 == c4, "is_nfkc({c1:?}), line {line}");
+            assert_eq!(is_nfkd(c1), c1 == c5, "is_nfkd({c1:?}), line {line}");
+        }
+        assert!(!part1.is_empty());
+
+        for ch in (0..=0x10FFFF).filter_map(char::from_u32) {
+            if part1.contains(&ch) {
+                continue;
+            }
+            let text = ch.to_string();
+            for form in NormalizationForm::ALL {
+                assert_eq!(
+                    normalize(&text, form),
+                    text,
+                    "{}(U+{:04X})",
+                    form.name(),
+                    ch as u32
+                );
+            }
+        }
+    }
+}
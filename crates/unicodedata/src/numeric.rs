@@ -0,0 +1,256 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::tables::{NUMERIC_TYPE, NUMERIC_VALUE, lookup};
+
+/// Value of the Numeric_Type property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericType {
+    /// A decimal digit, part of a contiguous run of digits from 0 to 9 (e.g. `٣`).
+    Decimal,
+    /// A digit that isn’t used in decimal positional notation (e.g. `³`).
+    Digit,
+    /// Any other number: fractions, Roman numerals, CJK numerals, etc.
+    Numeric,
+}
+
+impl NumericType {
+    /// Parse the abbreviation used in the Unicode Character Database.
+    fn from_abbr(abbr: &str) -> Option<Self> {
+        match abbr {
+            "De" => Some(Self::Decimal),
+            "Di" => Some(Self::Digit),
+            "Nu" => Some(Self::Numeric),
+            _ => None,
+        }
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Decimal => "De",
+            Self::Digit => "Di",
+            Self::Numeric => "Nu",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Decimal => "Decimal",
+            Self::Digit => "Digit",
+            Self::Numeric => "Numeric",
+        }
+    }
+}
+
+/// An exact numeric value, as a fraction in lowest terms.
+///
+/// The numerator is wide enough for the largest values in the UCD, such as `10^20`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericValue {
+    numerator: i128,
+    denominator: u64,
+}
+
+impl NumericValue {
+    /// Build a value from a numerator and a denominator, reducing it to lowest terms. Returns
+    /// `None` if the denominator is zero.
+    pub fn new(numerator: i128, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator.unsigned_abs(), u128::from(denominator)).max(1);
+        Some(Self {
+            numerator: numerator / divisor as i128,
+            denominator: (u128::from(denominator) / divisor) as u64,
+        })
+    }
+
+    /// Parse a value written as an integer (`10000`), a fraction (`-1/2`) or a decimal number
+    /// (`0.25`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let is_number = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, value),
+        };
+        if let Some((numerator, denominator)) = unsigned.split_once('/') {
+            if !is_number(numerator) || !is_number(denominator) {
+                return None;
+            }
+            Self::new(
+                sign * numerator.parse::<i128>().ok()?,
+                denominator.parse().ok()?,
+            )
+        } else {
+            let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+            if !is_number(integer) || !(fraction.is_empty() || is_number(fraction)) {
+                return None;
+            }
+            let digits = format!("{integer}{fraction}");
+            let denominator = 10u64.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+            Self::new(sign * digits.parse::<i128>().ok()?, denominator)
+        }
+    }
+
+    /// Numerator of the fraction in lowest terms; it carries the sign of the value.
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    /// Denominator of the fraction in lowest terms, always positive.
+    pub fn denominator(self) -> u64 {
+        self.denominator
+    }
+
+    /// Check whether the value is a whole number.
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Convert the value to a floating-point number, which may be inexact.
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+/// Greatest common divisor of two numbers.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl From<i64> for NumericValue {
+    fn from(value: i64) -> Self {
+        Self {
+            numerator: i128::from(value),
+            denominator: 1,
+        }
+    }
+}
+
+impl Ord for NumericValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Both denominators are positive, so cross-multiplying keeps the order.
+        let left = self.numerator * i128::from(other.denominator);
+        let right = other.numerator * i128::from(self.denominator);
+        left.cmp(&right)
+    }
+}
+
+impl PartialOrd for NumericValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for NumericValue {
+    /// Format the value as in the UCD: `5`, `1/4`, `-1/2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Get the numeric type of a codepoint, or `None` if it has no numeric value.
+pub fn numeric_type(codepoint: u32) -> Option<NumericType> {
+    lookup(&NUMERIC_TYPE, codepoint).and_then(|abbr| NumericType::from_abbr(abbr))
+}
+
+/// Get the numeric value of a codepoint (e.g. `1/4` for `¼`, `10000` for `万`), if it has one.
+pub fn numeric_value(codepoint: u32) -> Option<NumericValue> {
+    lookup(&NUMERIC_VALUE, codepoint).and_then(|value| NumericValue::parse(value))
+}
+
+/// Iterate over the codepoints with a given numeric value, in order.
+pub fn characters_with_value(value: NumericValue) -> impl Iterator<Item = u32> {
+    NUMERIC_VALUE
+        .iter()
+        .filter(move |(_, _, nv)| NumericValue::parse(nv) == Some(value))
+        .flat_map(|&(first, last, _)| first..=last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let v = |n, d| NumericValue::new(n, d);
+        assert_eq!(NumericValue::parse("5"), v(5, 1));
+        assert_eq!(NumericValue::parse("1/4"), v(1, 4));
+        assert_eq!(NumericValue::parse("-1/2"), v(-1, 2));
+        assert_eq!(NumericValue::parse("0.25"), v(1, 4));
+        assert_eq!(NumericValue::parse("2/4"), v(1, 2));
+        assert_eq!(
+            NumericValue::parse("100000000000000000000").map(NumericValue::numerator),
+            Some(10i128.pow(20))
+        );
+        assert_eq!(NumericValue::parse("NaN"), None);
+        assert_eq!(NumericValue::parse("1/0"), None);
+        assert_eq!(NumericValue::parse("1/-2"), None);
+        assert_eq!(NumericValue::parse(""), None);
+        assert_eq!(NumericValue::parse("."), None);
+    }
+
+    #[test]
+    fn test_value_display_and_order() {
+        assert_eq!(NumericValue::parse("3/6").unwrap().to_string(), "1/2");
+        assert_eq!(NumericValue::parse("-0.5").unwrap().to_string(), "-1/2");
+        assert_eq!(NumericValue::from(10000).to_string(), "10000");
+        assert!(NumericValue::parse("1/3").unwrap() < NumericValue::parse("1/2").unwrap());
+        assert!(NumericValue::parse("-1/2").unwrap() < NumericValue::from(0));
+        assert_eq!(NumericValue::parse("3/4").unwrap().to_f64(), 0.75);
+    }
+
+    #[test]
+    fn test_numeric_properties() {
+        assert_eq!(numeric_type(0x35), Some(NumericType::Decimal));
+        assert_eq!(numeric_value(0x35), Some(NumericValue::from(5)));
+        assert_eq!(numeric_type(0x663), Some(NumericType::Decimal)); // ARABIC-INDIC DIGIT THREE
+        assert_eq!(numeric_value(0x663), Some(NumericValue::from(3)));
+        assert_eq!(numeric_type(0xB3), Some(NumericType::Digit)); // SUPERSCRIPT THREE
+        assert_eq!(numeric_type(0xBC), Some(NumericType::Numeric));
+        assert_eq!(numeric_value(0xBC), NumericValue::new(1, 4));
+        assert_eq!(numeric_value(0x4E07), Some(NumericValue::from(10000))); // 万
+        assert_eq!(numeric_value(0x216B), Some(NumericValue::from(12))); // ROMAN NUMERAL TWELVE
+        assert_eq!(numeric_value(0xF33), NumericValue::new(-1, 2)); // TIBETAN DIGIT HALF ZERO
+        assert_eq!(numeric_type(0x41), None);
+        assert_eq!(numeric_value(0x41), None);
+    }
+
+    #[test]
+    fn test_characters_with_value() {
+        let fives: Vec<u32> = characters_with_value(NumericValue::from(5)).collect();
+        assert!(fives.contains(&0x35));
+        assert!(fives.contains(&0x665)); // ARABIC-INDIC DIGIT FIVE
+        assert!(fives.contains(&0x2164)); // ROMAN NUMERAL FIVE
+        assert!(fives.contains(&0x4E94)); // 五
+        assert!(!fives.contains(&0x36));
+        assert!(fives.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,177 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::blocks::{Block, blocks};
+use crate::tables::GENERAL_CATEGORY;
+
+/// Number of planes in the Unicode codespace.
+const PLANE_COUNT: u8 = 17;
+
+/// Number of codepoints in each plane.
+const PLANE_SIZE: u32 = 0x10000;
+
+/// One of the 17 planes of the Unicode codespace, each made of 65,536 codepoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Plane(u8);
+
+impl Plane {
+    /// Get a plane by its number, from 0 to 16.
+    pub fn new(number: u8) -> Option<Self> {
+        (number < PLANE_COUNT).then_some(Self(number))
+    }
+
+    /// Get the plane a codepoint belongs to, if it is within the codespace.
+    pub fn of(codepoint: u32) -> Option<Self> {
+        u8::try_from(codepoint / PLANE_SIZE)
+            .ok()
+            .and_then(Self::new)
+    }
+
+    /// Iterate over all planes, in order.
+    pub fn all() -> impl ExactSizeIterator<Item = Self> {
+        (0..PLANE_COUNT).map(Self)
+    }
+
+    /// Number of the plane, from 0 to 16.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Official name of the plane, if it has one; planes 4 to 13 are unassigned and have none.
+    pub fn name(self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Basic Multilingual Plane"),
+            1 => Some("Supplementary Multilingual Plane"),
+            2 => Some("Supplementary Ideographic Plane"),
+            3 => Some("Tertiary Ideographic Plane"),
+            14 => Some("Supplementary Special-purpose Plane"),
+            15 => Some("Supplementary Private Use Area-A"),
+            16 => Some("Supplementary Private Use Area-B"),
+            _ => None,
+        }
+    }
+
+    /// Abbreviation of the name of the plane (e.g. `BMP`), if it has one.
+    pub fn abbr(self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("BMP"),
+            1 => Some("SMP"),
+            2 => Some("SIP"),
+            3 => Some("TIP"),
+            14 => Some("SSP"),
+            15 => Some("SPUA-A"),
+            16 => Some("SPUA-B"),
+            _ => None,
+        }
+    }
+
+    /// First codepoint of the plane.
+    pub fn first(self) -> u32 {
+        u32::from(self.0) * PLANE_SIZE
+    }
+
+    /// Last codepoint of the plane.
+    pub fn last(self) -> u32 {
+        self.first() + PLANE_SIZE - 1
+    }
+
+    /// Range of codepoints in the plane.
+    pub fn codepoints(self) -> RangeInclusive<u32> {
+        self.first()..=self.last()
+    }
+
+    /// Number of assigned codepoints in the plane, i.e. those whose general category isn’t `Cn`.
+    pub fn assigned_count(self) -> u32 {
+        GENERAL_CATEGORY
+            .iter()
+            .map(|&(first, last, _)| {
+                let (first, last) = (first.max(self.first()), last.min(self.last()));
+                if first <= last { last - first + 1 } else { 0 }
+            })
+            .sum()
+    }
+
+    /// Iterate over the blocks in the plane.
+    pub fn blocks(self) -> impl Iterator<Item = Block> {
+        blocks().filter(move |block| self.codepoints().contains(&block.first))
+    }
+}
+
+impl fmt::Display for Plane {
+    /// Format the plane as its name, or as `Plane N` if it has none.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "Plane {}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plane_of() {
+        assert_eq!(Plane::of(0x41), Plane::new(0));
+        assert_eq!(Plane::of(0xFFFF), Plane::new(0));
+        assert_eq!(Plane::of(0x1F44D), Plane::new(1));
+        assert_eq!(Plane::of(0x10FFFF), Plane::new(16));
+        assert_eq!(Plane::of(0x110000), None);
+        assert_eq!(Plane::new(17), None);
+    }
+
+    #[test]
+    fn test_names() {
+        let bmp = Plane::new(0).unwrap();
+        assert_eq!(bmp.name(), Some("Basic Multilingual Plane"));
+        assert_eq!(bmp.abbr(), Some("BMP"));
+        assert_eq!(bmp.to_string(), "Basic Multilingual Plane");
+        let plane5 = Plane::new(5).unwrap();
+        assert_eq!(plane5.name(), None);
+        assert_eq!(plane5.abbr(), None);
+        assert_eq!(plane5.to_string(), "Plane 5");
+        assert_eq!(Plane::new(15).unwrap().abbr(), Some("SPUA-A"));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let smp = Plane::new(1).unwrap();
+        assert_eq!(smp.codepoints(), 0x10000..=0x1FFFF);
+        assert_eq!(Plane::all().count(), 17);
+        assert_eq!(Plane::all().last().unwrap().last(), 0x10FFFF);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let bmp = Plane::new(0).unwrap();
+        let assigned = bmp.assigned_count();
+        assert!(assigned > 55_000 && assigned < 0x10000, "{assigned}");
+        assert_eq!(Plane::new(5).unwrap().assigned_count(), 0);
+        // Every codepoint of the private use planes but the two noncharacters is assigned.
+        assert_eq!(Plane::new(15).unwrap().assigned_count(), 0xFFFE);
+        assert_eq!(bmp.blocks().next().unwrap().name, "Basic Latin");
+        let names: Vec<&str> = Plane::new(16).unwrap().blocks().map(|b| b.name).collect();
+        assert_eq!(names, ["Supplementary Private Use Area-B"]);
+        let total: usize = Plane::all().map(|plane| plane.blocks().count()).sum();
+        assert_eq!(total, blocks().len());
+    }
+}
@@ -0,0 +1,370 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::tables::{BINARY_PROPERTIES, lookup, loose_key};
+
+/// A binary (yes/no) property of the Unicode Character Database.
+///
+/// The properties are sorted by name, and the discriminant of each one is its bit in the
+/// generated bitset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinaryProperty {
+    AsciiHexDigit,
+    Alphabetic,
+    BidiControl,
+    BidiMirrored,
+    CaseIgnorable,
+    Cased,
+    ChangesWhenCasefolded,
+    ChangesWhenCasemapped,
+    ChangesWhenLowercased,
+    ChangesWhenNfkcCasefolded,
+    ChangesWhenTitlecased,
+    ChangesWhenUppercased,
+    CompositionExclusion,
+    Dash,
+    DefaultIgnorableCodePoint,
+    Deprecated,
+    Diacritic,
+    Emoji,
+    EmojiComponent,
+    EmojiModifier,
+    EmojiModifierBase,
+    EmojiPresentation,
+    ExtendedPictographic,
+    Extender,
+    FullCompositionExclusion,
+    GraphemeBase,
+    GraphemeExtend,
+    HexDigit,
+    Hyphen,
+    IdCompatMathContinue,
+    IdCompatMathStart,
+    IdContinue,
+    IdStart,
+    IdsBinaryOperator,
+    IdsTrinaryOperator,
+    IdsUnaryOperator,
+    Ideographic,
+    JoinControl,
+    LogicalOrderException,
+    Lowercase,
+    Math,
+    ModifierCombiningMark,
+    NoncharacterCodePoint,
+    PatternSyntax,
+    PatternWhiteSpace,
+    PrependedConcatenationMark,
+    QuotationMark,
+    Radical,
+    RegionalIndicator,
+    SentenceTerminal,
+    SoftDotted,
+    TerminalPunctuation,
+    UnifiedIdeograph,
+    Uppercase,
+    VariationSelector,
+    WhiteSpace,
+    XidContinue,
+    XidStart,
+}
+
+impl BinaryProperty {
+    /// All the binary properties, sorted by name.
+    pub const ALL: [Self; 58] = [
+        Self::AsciiHexDigit,
+        Self::Alphabetic,
+        Self::BidiControl,
+        Self::BidiMirrored,
+        Self::CaseIgnorable,
+        Self::Cased,
+        Self::ChangesWhenCasefolded,
+        Self::ChangesWhenCasemapped,
+        Self::ChangesWhenLowercased,
+        Self::ChangesWhenNfkcCasefolded,
+        Self::ChangesWhenTitlecased,
+        Self::ChangesWhenUppercased,
+        Self::CompositionExclusion,
+        Self::Dash,
+        Self::DefaultIgnorableCodePoint,
+        Self::Deprecated,
+        Self::Diacritic,
+        Self::Emoji,
+        Self::EmojiComponent,
+        Self::EmojiModifier,
+        Self::EmojiModifierBase,
+        Self::EmojiPresentation,
+        Self::ExtendedPictographic,
+        Self::Extender,
+        Self::FullCompositionExclusion,
+        Self::GraphemeBase,
+        Self::GraphemeExtend,
+        Self::HexDigit,
+        Self::Hyphen,
+        Self::IdCompatMathContinue,
+        Self::IdCompatMathStart,
+        Self::IdContinue,
+        Self::IdStart,
+        Self::IdsBinaryOperator,
+        Self::IdsTrinaryOperator,
+        Self::IdsUnaryOperator,
+        Self::Ideographic,
+        Self::JoinControl,
+        Self::LogicalOrderException,
+        Self::Lowercase,
+        Self::Math,
+        Self::ModifierCombiningMark,
+        Self::NoncharacterCodePoint,
+        Self::PatternSyntax,
+        Self::PatternWhiteSpace,
+        Self::PrependedConcatenationMark,
+        Self::QuotationMark,
+        Self::Radical,
+        Self::RegionalIndicator,
+        Self::SentenceTerminal,
+        Self::SoftDotted,
+        Self::TerminalPunctuation,
+        Self::UnifiedIdeograph,
+        Self::Uppercase,
+        Self::VariationSelector,
+        Self::WhiteSpace,
+        Self::XidContinue,
+        Self::XidStart,
+    ];
+
+    /// Find a binary property by its abbreviation (`WSpace`) or its name (`White_Space`), with
+    /// loose matching.
+    pub fn by_name(name: &str) -> Option<Self> {
+        let key = loose_key(name);
+        Self::ALL
+            .into_iter()
+            .find(|prop| loose_key(prop.abbr()) == key || loose_key(prop.name()) == key)
+    }
+
+    /// Abbreviation of the property, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::AsciiHexDigit => "AHex",
+            Self::Alphabetic => "Alpha",
+            Self::BidiControl => "Bidi_C",
+            Self::BidiMirrored => "Bidi_M",
+            Self::CaseIgnorable => "CI",
+            Self::Cased => "Cased",
+            Self::ChangesWhenCasefolded => "CWCF",
+            Self::ChangesWhenCasemapped => "CWCM",
+            Self::ChangesWhenLowercased => "CWL",
+            Self::ChangesWhenNfkcCasefolded => "CWKCF",
+            Self::ChangesWhenTitlecased => "CWT",
+            Self::ChangesWhenUppercased => "CWU",
+            Self::CompositionExclusion => "CE",
+            Self::Dash => "Dash",
+            Self::DefaultIgnorableCodePoint => "DI",
+            Self::Deprecated => "Dep",
+            Self::Diacritic => "Dia",
+            Self::Emoji => "Emoji",
+            Self::EmojiComponent => "EComp",
+            Self::EmojiModifier => "EMod",
+            Self::EmojiModifierBase => "EBase",
+            Self::EmojiPresentation => "EPres",
+            Self::ExtendedPictographic => "ExtPict",
+            Self::Extender => "Ext",
+            Self::FullCompositionExclusion => "Comp_Ex",
+            Self::GraphemeBase => "Gr_Base",
+            Self::GraphemeExtend => "Gr_Ext",
+            Self::HexDigit => "Hex",
+            Self::Hyphen => "Hyphen",
+            Self::IdCompatMathContinue => "ID_Compat_Math_Continue",
+            Self::IdCompatMathStart => "ID_Compat_Math_Start",
+            Self::IdContinue => "IDC",
+            Self::IdStart => "IDS",
+            Self::IdsBinaryOperator => "IDSB",
+            Self::IdsTrinaryOperator => "IDST",
+            Self::IdsUnaryOperator => "IDSU",
+            Self::Ideographic => "Ideo",
+            Self::JoinControl => "Join_C",
+            Self::LogicalOrderException => "LOE",
+            Self::Lowercase => "Lower",
+            Self::Math => "Math",
+            Self::ModifierCombiningMark => "MCM",
+            Self::NoncharacterCodePoint => "NChar",
+            Self::PatternSyntax => "Pat_Syn",
+            Self::PatternWhiteSpace => "Pat_WS",
+            Self::PrependedConcatenationMark => "PCM",
+            Self::QuotationMark => "QMark",
+            Self::Radical => "Radical",
+            Self::RegionalIndicator => "RI",
+            Self::SentenceTerminal => "STerm",
+            Self::SoftDotted => "SD",
+            Self::TerminalPunctuation => "Term",
+            Self::UnifiedIdeograph => "UIdeo",
+            Self::Uppercase => "Upper",
+            Self::VariationSelector => "VS",
+            Self::WhiteSpace => "WSpace",
+            Self::XidContinue => "XIDC",
+            Self::XidStart => "XIDS",
+        }
+    }
+
+    /// Full name of the property, with spaces instead of underscores.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AsciiHexDigit => "ASCII Hex Digit",
+            Self::Alphabetic => "Alphabetic",
+            Self::BidiControl => "Bidi Control",
+            Self::BidiMirrored => "Bidi Mirrored",
+            Self::CaseIgnorable => "Case Ignorable",
+            Self::Cased => "Cased",
+            Self::ChangesWhenCasefolded => "Changes When Casefolded",
+            Self::ChangesWhenCasemapped => "Changes When Casemapped",
+            Self::ChangesWhenLowercased => "Changes When Lowercased",
+            Self::ChangesWhenNfkcCasefolded => "Changes When NFKC Casefolded",
+            Self::ChangesWhenTitlecased => "Changes When Titlecased",
+            Self::ChangesWhenUppercased => "Changes When Uppercased",
+            Self::CompositionExclusion => "Composition Exclusion",
+            Self::Dash => "Dash",
+            Self::DefaultIgnorableCodePoint => "Default Ignorable Code Point",
+            Self::Deprecated => "Deprecated",
+            Self::Diacritic => "Diacritic",
+            Self::Emoji => "Emoji",
+            Self::EmojiComponent => "Emoji Component",
+            Self::EmojiModifier => "Emoji Modifier",
+            Self::EmojiModifierBase => "Emoji Modifier Base",
+            Self::EmojiPresentation => "Emoji Presentation",
+            Self::ExtendedPictographic => "Extended Pictographic",
+            Self::Extender => "Extender",
+            Self::FullCompositionExclusion => "Full Composition Exclusion",
+            Self::GraphemeBase => "Grapheme Base",
+            Self::GraphemeExtend => "Grapheme Extend",
+            Self::HexDigit => "Hex Digit",
+            Self::Hyphen => "Hyphen",
+            Self::IdCompatMathContinue => "ID Compat Math Continue",
+            Self::IdCompatMathStart => "ID Compat Math Start",
+            Self::IdContinue => "ID Continue",
+            Self::IdStart => "ID Start",
+            Self::IdsBinaryOperator => "IDS Binary Operator",
+            Self::IdsTrinaryOperator => "IDS Trinary Operator",
+            Self::IdsUnaryOperator => "IDS Unary Operator",
+            Self::Ideographic => "Ideographic",
+            Self::JoinControl => "Join Control",
+            Self::LogicalOrderException => "Logical Order Exception",
+            Self::Lowercase => "Lowercase",
+            Self::Math => "Math",
+            Self::ModifierCombiningMark => "Modifier Combining Mark",
+            Self::NoncharacterCodePoint => "Noncharacter Code Point",
+            Self::PatternSyntax => "Pattern Syntax",
+            Self::PatternWhiteSpace => "Pattern White Space",
+            Self::PrependedConcatenationMark => "Prepended Concatenation Mark",
+            Self::QuotationMark => "Quotation Mark",
+            Self::Radical => "Radical",
+            Self::RegionalIndicator => "Regional Indicator",
+            Self::SentenceTerminal => "Sentence Terminal",
+            Self::SoftDotted => "Soft Dotted",
+            Self::TerminalPunctuation => "Terminal Punctuation",
+            Self::UnifiedIdeograph => "Unified Ideograph",
+            Self::Uppercase => "Uppercase",
+            Self::VariationSelector => "Variation Selector",
+            Self::WhiteSpace => "White Space",
+            Self::XidContinue => "XID Continue",
+            Self::XidStart => "XID Start",
+        }
+    }
+
+    /// Bit of the property in the generated bitset.
+    fn bit(self) -> usize {
+        self as usize
+    }
+}
+
+/// Check whether a codepoint has a binary property.
+pub fn has_property(codepoint: u32, property: BinaryProperty) -> bool {
+    lookup(&BINARY_PROPERTIES, codepoint).is_some_and(|bits| bits >> property.bit() & 1 == 1)
+}
+
+/// List the binary properties a codepoint has, sorted by name.
+pub fn binary_properties(codepoint: u32) -> Vec<BinaryProperty> {
+    let Some(&bits) = lookup(&BINARY_PROPERTIES, codepoint) else {
+        return Vec::new();
+    };
+    BinaryProperty::ALL
+        .into_iter()
+        .filter(|prop| bits >> prop.bit() & 1 == 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::BINARY_PROPERTY_NAMES;
+
+    #[test]
+    fn test_bits_match_build_list() {
+        // The build script stores its list of attributes for this check only.
+        let abbrs: Vec<&str> = BinaryProperty::ALL.iter().map(|prop| prop.abbr()).collect();
+        assert_eq!(*BINARY_PROPERTY_NAMES, abbrs);
+        for (idx, prop) in BinaryProperty::ALL.into_iter().enumerate() {
+            assert_eq!(prop.bit(), idx);
+        }
+        assert!(
+            BinaryProperty::ALL
+                .windows(2)
+                .all(|pair| pair[0].name() < pair[1].name())
+        );
+    }
+
+    #[test]
+    fn test_by_name() {
+        let ws = Some(BinaryProperty::WhiteSpace);
+        assert_eq!(BinaryProperty::by_name("WSpace"), ws);
+        assert_eq!(BinaryProperty::by_name("White_Space"), ws);
+        assert_eq!(BinaryProperty::by_name("white space"), ws);
+        assert_eq!(
+            BinaryProperty::by_name("ExtPict"),
+            Some(BinaryProperty::ExtendedPictographic)
+        );
+        assert_eq!(BinaryProperty::by_name("Nonsense"), None);
+    }
+
+    #[test]
+    fn test_has_property() {
+        use BinaryProperty::*;
+        assert!(has_property(0x20, WhiteSpace));
+        assert!(has_property(0x20, PatternWhiteSpace));
+        assert!(!has_property(0x41, WhiteSpace));
+        assert!(has_property(0x41, Alphabetic));
+        assert!(has_property(0x41, AsciiHexDigit));
+        assert!(has_property(0x2D, Dash));
+        assert!(has_property(0x22, QuotationMark));
+        assert!(has_property(0x200D, JoinControl));
+        assert!(has_property(0xAD, DefaultIgnorableCodePoint));
+        assert!(has_property(0x1F600, Emoji));
+        assert!(has_property(0x1F600, EmojiPresentation));
+        assert!(has_property(0xFDD0, NoncharacterCodePoint));
+        assert!(has_property(0x4E00, UnifiedIdeograph));
+        assert!(!has_property(0x378, Alphabetic));
+    }
+
+    #[test]
+    fn test_binary_properties() {
+        use BinaryProperty::*;
+        let props = binary_properties(0x20);
+        assert!(props.contains(&WhiteSpace));
+        assert!(props.contains(&GraphemeBase));
+        assert!(!props.contains(&Alphabetic));
+        assert_eq!(binary_properties(0x378), []);
+    }
+}
@@ -0,0 +1,356 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use std::fmt;
+
+use crate::tables::{SCRIPT, SCRIPT_EXTENSIONS, lookup, loose_key};
+
+/// Codes of the scripts (ISO 15924) used by the `Script` property, with their names as given in
+/// `PropertyValueAliases.txt`, with spaces instead of underscores.
+const SCRIPT_NAMES: &[(&str, &str)] = &[
+    ("Adlm", "Adlam"),
+    ("Aghb", "Caucasian Albanian"),
+    ("Ahom", "Ahom"),
+    ("Arab", "Arabic"),
+    ("Armi", "Imperial Aramaic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Berf", "Beria Erfe"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cakm", "Chakma"),
+    ("Cans", "Canadian Aboriginal"),
+    ("Cari", "Carian"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chis", "Chisoi"),
+    ("Chrs", "Chorasmian"),
+    ("Copt", "Coptic"),
+    ("Cpmn", "Cypro Minoan"),
+    ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Gara", "Garay"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Gong", "Gunjala Gondi"),
+    ("Gonm", "Masaram Gondi"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gukh", "Gurung Khema"),
+    ("Guru", "Gurmukhi"),
+    ("Hang", "Hangul"),
+    ("Hani", "Han"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Hluw", "Anatolian Hieroglyphs"),
+    ("Hmng", "Pahawh Hmong"),
+    ("Hmnp", "Nyiakeng Puachue Hmong"),
+    ("Hrkt", "Katakana Or Hiragana"),
+    ("Hung", "Old Hungarian"),
+    ("Ital", "Old Italic"),
+    ("Java", "Javanese"),
+    ("Kali", "Kayah Li"),
+    ("Kana", "Katakana"),
+    ("Kawi", "Kawi"),
+    ("Khar", "Kharoshthi"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Kits", "Khitan Small Script"),
+    ("Knda", "Kannada"),
+    ("Krai", "Kirat Rai"),
+    ("Kthi", "Kaithi"),
+    ("Lana", "Tai Tham"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear A"),
+    ("Linb", "Linear B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Medf", "Medefaidrin"),
+    ("Mend", "Mende Kikakui"),
+    ("Merc", "Meroitic Cursive"),
+    ("Mero", "Meroitic Hieroglyphs"),
+    ("Mlym", "Malayalam"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mtei", "Meetei Mayek"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nagm", "Nag Mundari"),
+    ("Nand", "Nandinagari"),
+    ("Narb", "Old North Arabian"),
+    ("Nbat", "Nabataean"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol Chiki"),
+    ("Onao", "Ol Onal"),
+    ("Orkh", "Old Turkic"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Ougr", "Old Uyghur"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau Cin Hau"),
+    ("Perm", "Old Permic"),
+    ("Phag", "Phags Pa"),
+    ("Phli", "Inscriptional Pahlavi"),
+    ("Phlp", "Psalter Pahlavi"),
+    ("Phnx", "Phoenician"),
+    ("Plrd", "Miao"),
+    ("Prti", "Inscriptional Parthian"),
+    ("Rjng", "Rejang"),
+    ("Rohg", "Hanifi Rohingya"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Sarb", "Old South Arabian"),
+    ("Saur", "Saurashtra"),
+    ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"),
+    ("Shrd", "Sharada"),
+    ("Sidd", "Siddham"),
+    ("Sidt", "Sidetic"),
+    ("Sind", "Khudawadi"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sogo", "Old Sogdian"),
+    ("Sora", "Sora Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sunu", "Sunuwar"),
+    ("Sylo", "Syloti Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tagb", "Tagbanwa"),
+    ("Takr", "Takri"),
+    ("Tale", "Tai Le"),
+    ("Talu", "New Tai Lue"),
+    ("Taml", "Tamil"),
+    ("Tang", "Tangut"),
+    ("Tavt", "Tai Viet"),
+    ("Tayo", "Tai Yo"),
+    ("Telu", "Telugu"),
+    ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"),
+    ("Tnsa", "Tangsa"),
+    ("Todr", "Todhri"),
+    ("Tols", "Tolong Siki"),
+    ("Toto", "Toto"),
+    ("Tutg", "Tulu Tigalari"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wara", "Warang Citi"),
+    ("Wcho", "Wancho"),
+    ("Xpeo", "Old Persian"),
+    ("Xsux", "Cuneiform"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar Square"),
+    ("Zinh", "Inherited"),
+    ("Zyyy", "Common"),
+    ("Zzzz", "Unknown"),
+];
+
+/// Code of the script of codepoints not assigned to any script.
+const UNKNOWN: &str = "Zzzz";
+
+/// A script, as used by the `Script` and `Script_Extensions` properties (UAX #24).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Script {
+    code: &'static str,
+    name: &'static str,
+}
+
+/// Han with Bopomofo, one of the writing systems combining several scripts used by the augmented
+/// script sets of UTS #39.
+pub(crate) const HAN_WITH_BOPOMOFO: Script = Script {
+    code: "Hanb",
+    name: "Han with Bopomofo",
+};
+
+/// Japanese: Han, Hiragana and Katakana.
+pub(crate) const JAPANESE: Script = Script {
+    code: "Jpan",
+    name: "Japanese",
+};
+
+/// Korean: Han and Hangul.
+pub(crate) const KOREAN: Script = Script {
+    code: "Kore",
+    name: "Korean",
+};
+
+impl Script {
+    /// Get a script by its four-letter ISO 15924 code (e.g. `Latn`).
+    pub fn from_code(code: &str) -> Option<Self> {
+        SCRIPT_NAMES
+            .iter()
+            .find(|&&(c, _)| c == code)
+            .map(|&(code, name)| Self { code, name })
+    }
+
+    /// Find a script by its code or its name, matched loosely (e.g. `old italic` or `ital`).
+    pub fn by_name(name: &str) -> Option<Self> {
+        let key = loose_key(name);
+        SCRIPT_NAMES
+            .iter()
+            .find(|&&(code, name)| loose_key(code) == key || loose_key(name) == key)
+            .map(|&(code, name)| Self { code, name })
+    }
+
+    /// Iterate over all scripts known to the library, sorted by code.
+    pub fn all() -> impl ExactSizeIterator<Item = Self> {
+        SCRIPT_NAMES.iter().map(|&(code, name)| Self { code, name })
+    }
+
+    /// Create a script from a code found in the tables. Codes missing from [SCRIPT_NAMES] (from a
+    /// newer version of Unicode) are named after themselves.
+    fn from_table(code: &'static str) -> Self {
+        Self::from_code(code).unwrap_or(Self { code, name: code })
+    }
+
+    /// Four-letter ISO 15924 code of the script (e.g. `Latn`).
+    pub fn code(self) -> &'static str {
+        self.code
+    }
+
+    /// Name of the script (e.g. `Latin`).
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for Script {
+    /// Format the script as its name followed by its code, e.g. `Latin (Latn)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.code)
+    }
+}
+
+/// Get the script of a codepoint (its `Script` property).
+pub fn script(codepoint: u32) -> Script {
+    Script::from_table(lookup(&SCRIPT, codepoint).map_or(UNKNOWN, String::as_str))
+}
+
+/// Get the scripts a codepoint is used with (its `Script_Extensions` property).
+///
+/// For most characters, this is just their script; characters shared by a few scripts, such as
+/// the Arabic comma, list them all instead of `Common` or `Inherited`.
+pub fn script_extensions(codepoint: u32) -> Vec<Script> {
+    match lookup(&SCRIPT_EXTENSIONS, codepoint) {
+        Some(codes) => codes.split(' ').map(Script::from_table).collect(),
+        None => vec![script(codepoint)],
+    }
+}
+
+/// Iterate over the codepoints whose `Script` property is the given script, in order.
+///
+/// Characters that are merely used with the script, as listed in their script extensions, aren’t
+/// included.
+pub fn characters_in_script(script: Script) -> impl Iterator<Item = u32> {
+    SCRIPT
+        .iter()
+        .filter(move |(_, _, code)| code == script.code)
+        .flat_map(|&(first, last, _)| first..=last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_names_sorted() {
+        assert!(SCRIPT_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_script() {
+        assert_eq!(script(0x41).code(), "Latn");
+        assert_eq!(script(0x41).name(), "Latin");
+        assert_eq!(script(0x41).to_string(), "Latin (Latn)");
+        assert_eq!(script(0x5186).code(), "Hani");
+        assert_eq!(script(0x20).name(), "Common");
+        assert_eq!(script(0x0301).name(), "Inherited");
+        assert_eq!(script(0x0378).name(), "Unknown");
+    }
+
+    #[test]
+    fn test_script_extensions() {
+        assert_eq!(script_extensions(0x41), [script(0x41)]);
+        let codes: Vec<&str> = script_extensions(0x060C).iter().map(|s| s.code()).collect();
+        assert!(codes.contains(&"Arab"));
+        assert!(codes.contains(&"Syrc"));
+        assert!(!codes.contains(&"Zyyy"));
+    }
+
+    #[test]
+    fn test_by_name() {
+        let latin = Script::from_code("Latn");
+        assert!(latin.is_some());
+        assert_eq!(Script::by_name("latin"), latin);
+        assert_eq!(Script::by_name("LATN"), latin);
+        assert_eq!(Script::by_name("old_italic").unwrap().code(), "Ital");
+        assert_eq!(Script::by_name("Klingon"), None);
+        assert_eq!(Script::from_code("Xxxx"), None);
+    }
+
+    #[test]
+    fn test_characters_in_script() {
+        let georgian = Script::by_name("Georgian").unwrap();
+        let chars: Vec<u32> = characters_in_script(georgian).collect();
+        assert!(chars.contains(&0x10D0)); // GEORGIAN LETTER AN
+        assert!(chars.iter().all(|&cp| script(cp) == georgian));
+        assert!(!chars.contains(&0x10FB)); // GEORGIAN PARAGRAPH SEPARATOR is Common
+    }
+}
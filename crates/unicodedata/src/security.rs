@@ -0,0 +1,483 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Identifier profiles and mixed-script detection, as described in UTS #39 (Unicode Security
+//! Mechanisms).
+
+use crate::confusables::has_prototype;
+use crate::properties::{BinaryProperty, has_property};
+use crate::scripts::{HAN_WITH_BOPOMOFO, JAPANESE, KOREAN, Script, script, script_extensions};
+use crate::tables::{IDENTIFIER_STATUS, IDENTIFIER_TYPE, lookup};
+
+/// Scripts recommended for use in identifiers, from table 5 of UAX #31, by code.
+const RECOMMENDED_SCRIPTS: &[&str] = &[
+    "Arab", "Armn", "Beng", "Bopo", "Cyrl", "Deva", "Ethi", "Geor", "Grek", "Gujr", "Guru", "Hang",
+    "Hani", "Hebr", "Hira", "Kana", "Khmr", "Knda", "Laoo", "Latn", "Mlym", "Mymr", "Orya", "Sinh",
+    "Taml", "Telu", "Thaa", "Thai", "Tibt",
+];
+
+/// Whether a character may be used in identifiers, according to the general security profile of
+/// UTS #39 (the `Identifier_Status` property).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierStatus {
+    Allowed,
+    Restricted,
+}
+
+/// Why a character is or isn't allowed in identifiers (the `Identifier_Type` property).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierType {
+    NotCharacter,
+    Deprecated,
+    DefaultIgnorable,
+    NotNfkc,
+    NotXid,
+    Exclusion,
+    Obsolete,
+    Technical,
+    UncommonUse,
+    LimitedUse,
+    Inclusion,
+    Recommended,
+}
+
+impl IdentifierType {
+    /// Parse a value of the property, as found in `IdentifierType.txt`.
+    fn from_field(field: &str) -> Option<Self> {
+        match field {
+            "Not_Character" => Some(Self::NotCharacter),
+            "Deprecated" => Some(Self::Deprecated),
+            "Default_Ignorable" => Some(Self::DefaultIgnorable),
+            "Not_NFKC" => Some(Self::NotNfkc),
+            "Not_XID" => Some(Self::NotXid),
+            "Exclusion" => Some(Self::Exclusion),
+            "Obsolete" => Some(Self::Obsolete),
+            "Technical" => Some(Self::Technical),
+            "Uncommon_Use" => Some(Self::UncommonUse),
+            "Limited_Use" => Some(Self::LimitedUse),
+            "Inclusion" => Some(Self::Inclusion),
+            "Recommended" => Some(Self::Recommended),
+            _ => None,
+        }
+    }
+
+    /// Name of the value, with spaces instead of underscores.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NotCharacter => "Not Character",
+            Self::Deprecated => "Deprecated",
+            Self::DefaultIgnorable => "Default Ignorable",
+            Self::NotNfkc => "Not NFKC",
+            Self::NotXid => "Not XID",
+            Self::Exclusion => "Exclusion",
+            Self::Obsolete => "Obsolete",
+            Self::Technical => "Technical",
+            Self::UncommonUse => "Uncommon Use",
+            Self::LimitedUse => "Limited Use",
+            Self::Inclusion => "Inclusion",
+            Self::Recommended => "Recommended",
+        }
+    }
+}
+
+/// Get the identifier status of a codepoint. Characters not listed as allowed are restricted.
+pub fn identifier_status(codepoint: u32) -> IdentifierStatus {
+    match lookup(&IDENTIFIER_STATUS, codepoint).map(String::as_str) {
+        Some("Allowed") => IdentifierStatus::Allowed,
+        _ => IdentifierStatus::Restricted,
+    }
+}
+
+/// Get the identifier types of a codepoint. A character can have several, e.g. `Technical` and
+/// `Not NFKC`.
+pub fn identifier_types(codepoint: u32) -> Vec<IdentifierType> {
+    match lookup(&IDENTIFIER_TYPE, codepoint) {
+        Some(types) => types
+            .split_whitespace()
+            .filter_map(IdentifierType::from_field)
+            .collect(),
+        None => vec![IdentifierType::NotCharacter],
+    }
+}
+
+/// A set of scripts, as used to detect mixed-script strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptSet {
+    /// Every script, as for strings made only of characters shared by all scripts (e.g. digits).
+    All,
+    /// Only the given scripts, sorted by code. An empty set means the string mixes scripts.
+    Only(Vec<Script>),
+}
+
+impl ScriptSet {
+    /// Whether the set has no scripts at all.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Only(scripts) if scripts.is_empty())
+    }
+
+    /// Whether the set contains a script.
+    pub fn contains(&self, script: Script) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(scripts) => scripts.contains(&script),
+        }
+    }
+
+    /// Keep only the scripts also in another set.
+    fn intersect(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::All, set) | (set, Self::All) => set,
+            (Self::Only(scripts), other) => {
+                Self::Only(scripts.into_iter().filter(|&s| other.contains(s)).collect())
+            }
+        }
+    }
+}
+
+/// Augmented script set of a codepoint (section 5.1 of UTS #39): its script extensions, where
+/// Han, Hiragana, Katakana, Hangul and Bopomofo also count as the writing systems that combine
+/// them (Japanese, Korean and Han with Bopomofo).
+fn augmented_script_set(codepoint: u32) -> ScriptSet {
+    let mut scripts = Vec::new();
+    for script in script_extensions(codepoint) {
+        match script.code() {
+            "Zyyy" | "Zinh" => return ScriptSet::All,
+            "Hani" => scripts.extend([HAN_WITH_BOPOMOFO, JAPANESE, KOREAN]),
+            "Hira" | "Kana" => scripts.push(JAPANESE),
+            "Hang" => scripts.push(KOREAN),
+            "Bopo" => scripts.push(HAN_WITH_BOPOMOFO),
+            _ => {}
+        }
+        scripts.push(script);
+    }
+    scripts.sort_by_key(|script| script.code());
+    scripts.dedup();
+    ScriptSet::Only(scripts)
+}
+
+/// Resolved script set of a string (section 5.1 of UTS #39): the scripts that all of its
+/// characters can be used with. It is empty for mixed-script strings.
+pub fn resolved_script_set(text: &str) -> ScriptSet {
+    text.chars()
+        .map(|ch| augmented_script_set(u32::from(ch)))
+        .fold(ScriptSet::All, ScriptSet::intersect)
+}
+
+/// Restriction level of a string (section 5.2 of UTS #39), from the most to the least
+/// restrictive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RestrictionLevel {
+    /// Only ASCII characters.
+    AsciiOnly,
+    /// Characters of a single script, as given by the resolved script set.
+    SingleScript,
+    /// Latin mixed with Han, Hiragana and Katakana; with Han and Bopomofo; or with Han and Hangul.
+    HighlyRestrictive,
+    /// Latin mixed with a single other recommended script, other than Cyrillic or Greek.
+    ModeratelyRestrictive,
+    /// Any mixture of scripts.
+    MinimallyRestrictive,
+    /// Characters outside the general security profile for identifiers.
+    Unrestricted,
+}
+
+impl RestrictionLevel {
+    /// Name of the restriction level.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AsciiOnly => "ASCII-Only",
+            Self::SingleScript => "Single Script",
+            Self::HighlyRestrictive => "Highly Restrictive",
+            Self::ModeratelyRestrictive => "Moderately Restrictive",
+            Self::MinimallyRestrictive => "Minimally Restrictive",
+            Self::Unrestricted => "Unrestricted",
+        }
+    }
+}
+
+/// Compute the restriction level of a string, following section 5.2 of UTS #39.
+fn restriction_level(text: &str, resolved: &ScriptSet) -> RestrictionLevel {
+    let codepoints: Vec<u32> = text.chars().map(u32::from).collect();
+    if codepoints
+        .iter()
+        .any(|&cp| identifier_status(cp) == IdentifierStatus::Restricted)
+    {
+        return RestrictionLevel::Unrestricted;
+    }
+    if codepoints.iter().all(|&cp| cp <= 0x7E) {
+        return RestrictionLevel::AsciiOnly;
+    }
+    if !resolved.is_empty() {
+        return RestrictionLevel::SingleScript;
+    }
+    // What the string would resolve to without its Latin characters.
+    let latin = Script::from_code("Latn").unwrap();
+    let others = codepoints
+        .iter()
+        .map(|&cp| augmented_script_set(cp))
+        .filter(|set| !set.contains(latin))
+        .fold(ScriptSet::All, ScriptSet::intersect);
+    if [HAN_WITH_BOPOMOFO, JAPANESE, KOREAN]
+        .iter()
+        .any(|&script| others.contains(script))
+    {
+        return RestrictionLevel::HighlyRestrictive;
+    }
+    if let ScriptSet::Only(scripts) = &others
+        && scripts.iter().any(|script| {
+            RECOMMENDED_SCRIPTS.contains(&script.code())
+                && !["Cyrl", "Grek"].contains(&script.code())
+        })
+    {
+        return RestrictionLevel::ModeratelyRestrictive;
+    }
+    RestrictionLevel::MinimallyRestrictive
+}
+
+/// Zero width characters that are not format controls of some other kind: ZERO WIDTH SPACE,
+/// ZERO WIDTH NON-JOINER, ZERO WIDTH JOINER, WORD JOINER and ZERO WIDTH NO-BREAK SPACE.
+const ZERO_WIDTH: &[u32] = &[0x200B, 0x200C, 0x200D, 0x2060, 0xFEFF];
+
+/// Tag characters, used to spell out emoji tag sequences, and invisible anywhere else.
+const TAGS: std::ops::RangeInclusive<u32> = 0xE0000..=0xE007F;
+
+/// Why a character stands out in a string, as characters used to hide or disguise text, such as
+/// in Trojan Source attacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterFlag {
+    /// A bidirectional formatting character (override, embedding, isolate or mark), which can
+    /// reorder the text around it.
+    BidiControl,
+    /// A zero width space, joiner or non-joiner.
+    ZeroWidth,
+    /// A tag character.
+    Tag,
+    /// A variation selector, which changes the glyph of the preceding character.
+    VariationSelector,
+    /// Any other character that is normally not shown at all (a default ignorable code point).
+    Invisible,
+    /// A non-ASCII character that can be mistaken for another one (UTS #39), e.g. the Cyrillic
+    /// `а` for the Latin `a`.
+    Homoglyph,
+}
+
+impl CharacterFlag {
+    /// Find why a character stands out, if it does.
+    pub fn of(codepoint: u32) -> Option<Self> {
+        if has_property(codepoint, BinaryProperty::BidiControl) {
+            Some(Self::BidiControl)
+        } else if ZERO_WIDTH.contains(&codepoint) {
+            Some(Self::ZeroWidth)
+        } else if TAGS.contains(&codepoint) {
+            Some(Self::Tag)
+        } else if has_property(codepoint, BinaryProperty::VariationSelector) {
+            Some(Self::VariationSelector)
+        } else if has_property(codepoint, BinaryProperty::DefaultIgnorableCodePoint) {
+            Some(Self::Invisible)
+        } else if codepoint > 0x7F && has_prototype(codepoint) {
+            Some(Self::Homoglyph)
+        } else {
+            None
+        }
+    }
+
+    /// Name of the flag.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BidiControl => "Bidi Control",
+            Self::ZeroWidth => "Zero Width",
+            Self::Tag => "Tag",
+            Self::VariationSelector => "Variation Selector",
+            Self::Invisible => "Invisible",
+            Self::Homoglyph => "Homoglyph",
+        }
+    }
+}
+
+/// A character of a string that stands out, found by [`analyze_string`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlaggedCharacter {
+    /// Offset of the character in the string, in bytes.
+    pub offset: usize,
+    pub codepoint: u32,
+    pub flag: CharacterFlag,
+}
+
+/// Result of the analysis of a string by [`analyze_string`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringAnalysis {
+    /// Scripts of the characters, other than Common and Inherited, in order of appearance.
+    pub scripts: Vec<Script>,
+    /// Resolved script set of the string.
+    pub resolved_scripts: ScriptSet,
+    pub restriction_level: RestrictionLevel,
+    /// Characters that aren't allowed in identifiers, in order of appearance, without repetitions.
+    pub restricted: Vec<u32>,
+    /// Invisible, bidi control and homoglyph characters, in order of appearance.
+    pub flagged: Vec<FlaggedCharacter>,
+}
+
+impl StringAnalysis {
+    /// Whether the string mixes characters of scripts that can't be used together.
+    pub fn is_mixed_script(&self) -> bool {
+        self.resolved_scripts.is_empty()
+    }
+}
+
+/// Analyze a string, e.g. a user name, for the mixtures of scripts and the characters that are
+/// used in spoofing attacks.
+pub fn analyze_string(text: &str) -> StringAnalysis {
+    let mut scripts = Vec::new();
+    let mut restricted = Vec::new();
+    let mut flagged = Vec::new();
+    for (offset, ch) in text.char_indices() {
+        let codepoint = u32::from(ch);
+        let script = script(codepoint);
+        if !["Zyyy", "Zinh"].contains(&script.code()) && !scripts.contains(&script) {
+            scripts.push(script);
+        }
+        if identifier_status(codepoint) == IdentifierStatus::Restricted
+            && !restricted.contains(&codepoint)
+        {
+            restricted.push(codepoint);
+        }
+        if let Some(flag) = CharacterFlag::of(codepoint) {
+            flagged.push(FlaggedCharacter {
+                offset,
+                codepoint,
+                flag,
+            });
+        }
+    }
+    let resolved_scripts = resolved_script_set(text);
+    StringAnalysis {
+        scripts,
+        restriction_level: restriction_level(text, &resolved_scripts),
+        resolved_scripts,
+        restricted,
+        flagged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scripts(codes: &[&str]) -> ScriptSet {
+        ScriptSet::Only(
+            codes
+                .iter()
+                .map(|&code| Script::from_code(code).unwrap())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_identifier_status() {
+        assert_eq!(identifier_status(0x61), IdentifierStatus::Allowed);
+        assert_eq!(identifier_status(0x430), IdentifierStatus::Allowed);
+        assert_eq!(identifier_status(0x20), IdentifierStatus::Restricted);
+        assert_eq!(identifier_status(0x202E), IdentifierStatus::Restricted);
+    }
+
+    #[test]
+    fn test_identifier_types() {
+        assert_eq!(identifier_types(0x61), [IdentifierType::Recommended]);
+        assert_eq!(identifier_types(0x20), [IdentifierType::NotXid]);
+        assert_eq!(identifier_types(0x200B), [IdentifierType::DefaultIgnorable]);
+        assert_eq!(identifier_types(0xFFFF), [IdentifierType::NotCharacter]);
+        assert_eq!(IdentifierType::NotNfkc.name(), "Not NFKC");
+    }
+
+    #[test]
+    fn test_resolved_script_set() {
+        assert_eq!(resolved_script_set("abc"), scripts(&["Latn"]));
+        assert_eq!(resolved_script_set("123"), ScriptSet::All);
+        assert_eq!(resolved_script_set("abc123"), scripts(&["Latn"]));
+        assert!(resolved_script_set("pаypal").is_empty());
+        let japanese = resolved_script_set("漢字かな");
+        assert_eq!(japanese, ScriptSet::Only(vec![JAPANESE]));
+        assert!(resolved_script_set("한국어").contains(KOREAN));
+    }
+
+    #[test]
+    fn test_restriction_level() {
+        use RestrictionLevel::*;
+        let level = |text| analyze_string(text).restriction_level;
+        assert_eq!(level("paypal"), AsciiOnly);
+        assert_eq!(level("παράδειγμα"), SingleScript);
+        assert_eq!(level("café"), SingleScript);
+        assert_eq!(level("abcかな"), HighlyRestrictive);
+        assert_eq!(level("abcשלום"), ModeratelyRestrictive);
+        assert_eq!(level("pаypal"), MinimallyRestrictive);
+        assert_eq!(level("pay pal"), Unrestricted);
+        assert!(AsciiOnly < Unrestricted);
+    }
+
+    #[test]
+    fn test_character_flag() {
+        use CharacterFlag::*;
+        assert_eq!(CharacterFlag::of(0x61), None);
+        assert_eq!(CharacterFlag::of(0x31), None);
+        assert_eq!(CharacterFlag::of(0x202E), Some(BidiControl));
+        assert_eq!(CharacterFlag::of(0x2067), Some(BidiControl));
+        assert_eq!(CharacterFlag::of(0x200D), Some(ZeroWidth));
+        assert_eq!(CharacterFlag::of(0xE0041), Some(Tag));
+        assert_eq!(CharacterFlag::of(0xFE0F), Some(VariationSelector));
+        assert_eq!(CharacterFlag::of(0xAD), Some(Invisible));
+        assert_eq!(CharacterFlag::of(0x430), Some(Homoglyph));
+    }
+
+    #[test]
+    fn test_analyze_string() {
+        let analysis = analyze_string("pаypal");
+        assert!(analysis.is_mixed_script());
+        assert_eq!(
+            analysis.scripts,
+            [
+                Script::from_code("Latn").unwrap(),
+                Script::from_code("Cyrl").unwrap()
+            ]
+        );
+        assert!(analysis.restricted.is_empty());
+        let analysis = analyze_string("a\u{202E}b\u{200B}c");
+        assert_eq!(
+            analysis.flagged,
+            [
+                FlaggedCharacter {
+                    offset: 1,
+                    codepoint: 0x202E,
+                    flag: CharacterFlag::BidiControl
+                },
+                FlaggedCharacter {
+                    offset: 5,
+                    codepoint: 0x200B,
+                    flag: CharacterFlag::ZeroWidth
+                },
+            ]
+        );
+        assert_eq!(analysis.restricted, [0x202E, 0x200B]);
+        let flagged = analyze_string("pаypal").flagged;
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(
+            (flagged[0].codepoint, flagged[0].flag),
+            (0x430, CharacterFlag::Homoglyph)
+        );
+        assert!(!analysis.is_mixed_script());
+    }
+}
@@ -0,0 +1,262 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Tables generated from the Unicode Character Database by the build script.
+//!
+//! Most tables are lists of ranges of codepoints, sorted by codepoint, optionally with a value for
+//! each range. Codepoints not covered by any range have the default value of the property.
+
+use lazy_static::lazy_static;
+
+/// Decode a table embedded in the binary.
+fn decode<T: bincode::Decode<()>>(bytes: &[u8]) -> T {
+    bincode::decode_from_slice(bytes, bincode::config::standard())
+        .unwrap()
+        .0
+}
+
+/// Decode a table embedded in the binary compressed with DEFLATE.
+#[cfg(feature = "unihan")]
+fn decode_compressed<T: bincode::Decode<()>>(bytes: &[u8]) -> T {
+    let mut decoder = flate2::read::DeflateDecoder::new(bytes);
+    bincode::decode_from_std_read(&mut decoder, bincode::config::standard()).unwrap()
+}
+
+lazy_static! {
+    pub(crate) static ref NAMES: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NAMES_FILE")));
+    pub(crate) static ref GENERAL_CATEGORY: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_GENERAL_CATEGORY_FILE")));
+    pub(crate) static ref EAST_ASIAN_WIDTH: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_EAST_ASIAN_WIDTH_FILE")));
+    pub(crate) static ref BINARY_PROPERTIES: Vec<(u32, u32, u64)> =
+        decode(include_bytes!(env!("UNICODEDATA_BINARY_PROPERTIES_FILE")));
+    pub(crate) static ref BLOCKS: Vec<(u32, u32, String, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_BLOCKS_FILE")));
+    pub(crate) static ref SCRIPT: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_SCRIPT_FILE")));
+    pub(crate) static ref SCRIPT_EXTENSIONS: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_SCRIPT_EXTENSIONS_FILE")));
+    pub(crate) static ref AGE: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_AGE_FILE")));
+    pub(crate) static ref DECOMPOSITION: Vec<(u32, String, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_DECOMPOSITION_FILE")));
+    pub(crate) static ref COMBINING_CLASS: Vec<(u32, u32, u8)> =
+        decode(include_bytes!(env!("UNICODEDATA_COMBINING_CLASS_FILE")));
+    pub(crate) static ref COMPOSITION: Vec<(u32, u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_COMPOSITION_FILE")));
+    pub(crate) static ref NFC_QUICK_CHECK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NFC_QUICK_CHECK_FILE")));
+    pub(crate) static ref NFD_QUICK_CHECK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NFD_QUICK_CHECK_FILE")));
+    pub(crate) static ref NFKC_QUICK_CHECK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NFKC_QUICK_CHECK_FILE")));
+    pub(crate) static ref NFKD_QUICK_CHECK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NFKD_QUICK_CHECK_FILE")));
+    pub(crate) static ref SIMPLE_UPPERCASE: Vec<(u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_SIMPLE_UPPERCASE_FILE")));
+    pub(crate) static ref SIMPLE_LOWERCASE: Vec<(u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_SIMPLE_LOWERCASE_FILE")));
+    pub(crate) static ref SIMPLE_TITLECASE: Vec<(u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_SIMPLE_TITLECASE_FILE")));
+    pub(crate) static ref SIMPLE_CASE_FOLDING: Vec<(u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_SIMPLE_CASE_FOLDING_FILE")));
+    pub(crate) static ref UPPERCASE: Vec<(u32, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_UPPERCASE_FILE")));
+    pub(crate) static ref LOWERCASE: Vec<(u32, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_LOWERCASE_FILE")));
+    pub(crate) static ref TITLECASE: Vec<(u32, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_TITLECASE_FILE")));
+    pub(crate) static ref CASE_FOLDING: Vec<(u32, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_CASE_FOLDING_FILE")));
+    pub(crate) static ref BIDI_CLASS: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_BIDI_CLASS_FILE")));
+    pub(crate) static ref BIDI_MIRRORING_GLYPH: Vec<(u32, u32)> = decode(include_bytes!(env!(
+        "UNICODEDATA_BIDI_MIRRORING_GLYPH_FILE"
+    )));
+    pub(crate) static ref BIDI_PAIRED_BRACKET_TYPE: Vec<(u32, u32, String)> = decode(
+        include_bytes!(env!("UNICODEDATA_BIDI_PAIRED_BRACKET_TYPE_FILE"))
+    );
+    pub(crate) static ref BIDI_PAIRED_BRACKET: Vec<(u32, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_BIDI_PAIRED_BRACKET_FILE")));
+    pub(crate) static ref LINE_BREAK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_LINE_BREAK_FILE")));
+    pub(crate) static ref WORD_BREAK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_WORD_BREAK_FILE")));
+    pub(crate) static ref SENTENCE_BREAK: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_SENTENCE_BREAK_FILE")));
+    pub(crate) static ref GRAPHEME_CLUSTER_BREAK: Vec<(u32, u32, String)> = decode(include_bytes!(
+        env!("UNICODEDATA_GRAPHEME_CLUSTER_BREAK_FILE")
+    ));
+    pub(crate) static ref INDIC_CONJUNCT_BREAK: Vec<(u32, u32, String)> = decode(include_bytes!(
+        env!("UNICODEDATA_INDIC_CONJUNCT_BREAK_FILE")
+    ));
+    pub(crate) static ref EMOJI_SEQUENCES: Vec<(Vec<u32>, String, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_EMOJI_SEQUENCES_FILE")));
+    pub(crate) static ref UNQUALIFIED_EMOJI: Vec<(Vec<u32>, u32)> =
+        decode(include_bytes!(env!("UNICODEDATA_UNQUALIFIED_EMOJI_FILE")));
+    pub(crate) static ref JAMO_SHORT_NAME: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_JAMO_SHORT_NAME_FILE")));
+    pub(crate) static ref NUMERIC_TYPE: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NUMERIC_TYPE_FILE")));
+    pub(crate) static ref NUMERIC_VALUE: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_NUMERIC_VALUE_FILE")));
+    pub(crate) static ref CONFUSABLES: Vec<(u32, Vec<u32>)> =
+        decode(include_bytes!(env!("UNICODEDATA_CONFUSABLES_FILE")));
+    pub(crate) static ref IDENTIFIER_STATUS: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_IDENTIFIER_STATUS_FILE")));
+    pub(crate) static ref IDENTIFIER_TYPE: Vec<(u32, u32, String)> =
+        decode(include_bytes!(env!("UNICODEDATA_IDENTIFIER_TYPE_FILE")));
+}
+
+// Only the tests need the attributes in the bitset, to check them against [crate::BinaryProperty].
+#[cfg(test)]
+lazy_static! {
+    pub(crate) static ref BINARY_PROPERTY_NAMES: Vec<String> = decode(include_bytes!(env!(
+        "UNICODEDATA_BINARY_PROPERTY_NAMES_FILE"
+    )));
+}
+
+#[cfg(feature = "unihan")]
+lazy_static! {
+    pub(crate) static ref UNIHAN: Vec<(u32, Vec<(String, String)>)> =
+        decode_compressed(include_bytes!(env!("UNICODEDATA_UNIHAN_FILE")));
+}
+
+/// Find the value of the range containing a codepoint.
+pub(crate) fn lookup<V>(table: &[(u32, u32, V)], codepoint: u32) -> Option<&V> {
+    let idx = table.partition_point(|&(_, last, _)| last < codepoint);
+    table
+        .get(idx)
+        .filter(|&&(first, _, _)| first <= codepoint)
+        .map(|(_, _, value)| value)
+}
+
+/// Find the value of a codepoint in a table of single codepoints, sorted by codepoint.
+pub(crate) fn lookup_codepoint<V>(table: &[(u32, V)], codepoint: u32) -> Option<&V> {
+    table
+        .binary_search_by_key(&codepoint, |(cp, _)| *cp)
+        .ok()
+        .map(|idx| &table[idx].1)
+}
+
+/// Reduce a property value to the form used for loose matching, as described in UAX44-LM3:
+/// case, whitespace, underscores, hyphens and an initial “is” are ignored.
+pub(crate) fn loose_key(value: &str) -> String {
+    let key: String = value
+        .chars()
+        .filter(|&c| !(c.is_whitespace() || c == '_' || c == '-'))
+        .flat_map(char::to_lowercase)
+        .collect();
+    match key.strip_prefix("is") {
+        Some(rest) if !rest.is_empty() => rest.to_string(),
+        _ => key,
+    }
+}
+
+/// General category of a codepoint, as its two-letter abbreviation.
+pub(crate) fn general_category_abbr(codepoint: u32) -> &'static str {
+    lookup(&GENERAL_CATEGORY, codepoint).map_or("Cn", String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_loaded() {
+        assert!(!NAMES.is_empty());
+        assert!(!GENERAL_CATEGORY.is_empty());
+        assert!(!EAST_ASIAN_WIDTH.is_empty());
+        assert!(!BINARY_PROPERTY_NAMES.is_empty());
+        assert!(!BINARY_PROPERTIES.is_empty());
+        assert!(!BLOCKS.is_empty());
+        assert!(!SCRIPT.is_empty());
+        assert!(!SCRIPT_EXTENSIONS.is_empty());
+        assert!(!AGE.is_empty());
+        assert!(!DECOMPOSITION.is_empty());
+        assert!(!COMBINING_CLASS.is_empty());
+        assert!(!COMPOSITION.is_empty());
+        assert!(!NFC_QUICK_CHECK.is_empty());
+        assert!(!NFD_QUICK_CHECK.is_empty());
+        assert!(!NFKC_QUICK_CHECK.is_empty());
+        assert!(!NFKD_QUICK_CHECK.is_empty());
+        assert!(!SIMPLE_UPPERCASE.is_empty());
+        assert!(!SIMPLE_LOWERCASE.is_empty());
+        assert!(!SIMPLE_TITLECASE.is_empty());
+        assert!(!SIMPLE_CASE_FOLDING.is_empty());
+        assert!(!UPPERCASE.is_empty());
+        assert!(!LOWERCASE.is_empty());
+        assert!(!TITLECASE.is_empty());
+        assert!(!CASE_FOLDING.is_empty());
+        assert!(!BIDI_CLASS.is_empty());
+        assert!(!BIDI_MIRRORING_GLYPH.is_empty());
+        assert!(!BIDI_PAIRED_BRACKET_TYPE.is_empty());
+        assert!(!BIDI_PAIRED_BRACKET.is_empty());
+        assert!(!LINE_BREAK.is_empty());
+        assert!(!WORD_BREAK.is_empty());
+        assert!(!SENTENCE_BREAK.is_empty());
+        assert!(!GRAPHEME_CLUSTER_BREAK.is_empty());
+        // Indic_Conjunct_Break only exists since Unicode 15.1, so it may be empty.
+        assert!(!EMOJI_SEQUENCES.is_empty());
+        assert_eq!(UNQUALIFIED_EMOJI.len(), EMOJI_SEQUENCES.len());
+        assert!(!JAMO_SHORT_NAME.is_empty());
+        assert!(!NUMERIC_TYPE.is_empty());
+        assert!(!NUMERIC_VALUE.is_empty());
+        assert!(!CONFUSABLES.is_empty());
+        assert!(!IDENTIFIER_STATUS.is_empty());
+        assert!(!IDENTIFIER_TYPE.is_empty());
+        #[cfg(feature = "unihan")]
+        assert!(!UNIHAN.is_empty());
+    }
+
+    #[test]
+    fn test_lookup() {
+        let table = [(0x10, 0x1F, 'a'), (0x20, 0x20, 'b'), (0x30, 0x3F, 'c')];
+        assert_eq!(lookup(&table, 0x0F), None);
+        assert_eq!(lookup(&table, 0x10), Some(&'a'));
+        assert_eq!(lookup(&table, 0x20), Some(&'b'));
+        assert_eq!(lookup(&table, 0x21), None);
+        assert_eq!(lookup(&table, 0x3F), Some(&'c'));
+        assert_eq!(lookup(&table, 0x40), None);
+    }
+
+    #[test]
+    fn test_lookup_codepoint() {
+        let table = [(0x10, 'a'), (0x20, 'b')];
+        assert_eq!(lookup_codepoint(&table, 0x10), Some(&'a'));
+        assert_eq!(lookup_codepoint(&table, 0x15), None);
+        assert_eq!(lookup_codepoint(&table, 0x20), Some(&'b'));
+    }
+
+    #[test]
+    fn test_loose_key() {
+        assert_eq!(loose_key("Latin-1 Supplement"), "latin1supplement");
+        assert_eq!(loose_key("latin_1_SUPPLEMENT"), "latin1supplement");
+        assert_eq!(loose_key("isLatin1Supplement"), "latin1supplement");
+        assert_eq!(loose_key("is"), "is");
+    }
+
+    #[test]
+    fn test_general_category_abbr() {
+        assert_eq!(general_category_abbr(0x41), "Lu");
+        assert_eq!(general_category_abbr(0x5186), "Lo");
+        assert_eq!(general_category_abbr(0x0301), "Mn");
+        assert_eq!(general_category_abbr(0x0378), "Cn");
+    }
+}
@@ -0,0 +1,209 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+//! Readings, definitions and radical-stroke counts of CJK ideographs, from the Unihan database
+//! (UAX #38). Only available with the `unihan` feature.
+
+use std::fmt;
+
+use crate::decomposition::decomposition;
+use crate::tables::{UNIHAN, lookup_codepoint};
+
+/// First of the KangXi radicals (U+2F00 to U+2FD5), in the order of the dictionary.
+const KANGXI_RADICALS_START: u32 = 0x2F00;
+
+/// Number of radicals of the KangXi dictionary.
+const KANGXI_RADICALS_COUNT: u8 = 214;
+
+/// Radical-stroke count of an ideograph (`kRSUnicode`): its radical, numbered as in the KangXi
+/// dictionary, and the number of strokes besides the radical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RadicalStroke {
+    /// Number of the radical, from 1 to 214.
+    pub radical: u8,
+    /// Whether the ideograph uses a simplified form of the radical (e.g. 讠 for 言).
+    pub simplified: bool,
+    /// Number of residual strokes.
+    pub strokes: u8,
+}
+
+impl RadicalStroke {
+    /// Parse a value of `kRSUnicode`, e.g. `13.2` or `149'.7`.
+    fn parse(value: &str) -> Option<Self> {
+        let (radical, strokes) = value.split_once('.')?;
+        let trimmed = radical.trim_end_matches('\'');
+        Some(Self {
+            radical: trimmed.parse().ok()?,
+            simplified: trimmed.len() != radical.len(),
+            strokes: strokes.parse().ok()?,
+        })
+    }
+
+    /// The KangXi radical character (U+2F00 to U+2FD5) for the radical. Simplified forms of the
+    /// radicals have none.
+    pub fn radical_character(self) -> Option<char> {
+        if self.simplified || !(1..=KANGXI_RADICALS_COUNT).contains(&self.radical) {
+            return None;
+        }
+        char::from_u32(KANGXI_RADICALS_START + u32::from(self.radical) - 1)
+    }
+}
+
+impl fmt::Display for RadicalStroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = if self.simplified { "'" } else { "" };
+        write!(f, "{}{mark}.{}", self.radical, self.strokes)
+    }
+}
+
+/// Unihan data of an ideograph. Fields missing from the database are empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Unihan {
+    /// English definition (`kDefinition`), e.g. `yen; circle; round`.
+    pub definition: Option<&'static str>,
+    /// Mandarin readings in Hanyu Pinyin (`kMandarin`), most customary first.
+    pub mandarin: Vec<&'static str>,
+    /// Cantonese readings in Jyutping (`kCantonese`).
+    pub cantonese: Vec<&'static str>,
+    /// Sino-Japanese readings (`kJapaneseOn`), in uppercase Hepburn.
+    pub japanese_on: Vec<&'static str>,
+    /// Native Japanese readings (`kJapaneseKun`), in uppercase Hepburn.
+    pub japanese_kun: Vec<&'static str>,
+    /// Korean readings in Hangul (`kHangul`).
+    pub hangul: Vec<&'static str>,
+    /// Total number of strokes (`kTotalStrokes`), as usually counted in China.
+    pub total_strokes: Option<u8>,
+    /// Radical-stroke counts (`kRSUnicode`), the first being the one used to order the character
+    /// in the Unicode code charts.
+    pub radical_stroke: Vec<RadicalStroke>,
+}
+
+/// Get the Unihan data of an ideograph, if the database has any.
+pub fn unihan(codepoint: u32) -> Option<Unihan> {
+    let fields = lookup_codepoint(&UNIHAN, codepoint)?;
+    let mut unihan = Unihan::default();
+    for (field, value) in fields {
+        let values = value.split_whitespace();
+        match field.as_str() {
+            "kDefinition" => unihan.definition = Some(value),
+            "kMandarin" => unihan.mandarin = values.collect(),
+            "kCantonese" => unihan.cantonese = values.collect(),
+            "kJapaneseOn" => unihan.japanese_on = values.collect(),
+            "kJapaneseKun" => unihan.japanese_kun = values.collect(),
+            // Each reading is followed by the sources where it was found, e.g. `원:0N`.
+            "kHangul" => {
+                unihan.hangul = values
+                    .map(|reading| reading.split(':').next().unwrap_or(reading))
+                    .collect();
+            }
+            "kTotalStrokes" => {
+                unihan.total_strokes = values.map(str::parse).next().and_then(Result::ok);
+            }
+            "kRSUnicode" => {
+                unihan.radical_stroke = values.filter_map(RadicalStroke::parse).collect()
+            }
+            _ => {}
+        }
+    }
+    Some(unihan)
+}
+
+/// Iterate over the ideographs with a radical, and optionally a number of residual strokes, in
+/// any of their radical-stroke counts. Ideographs with a simplified form of the radical are
+/// included.
+pub fn characters_with_radical(radical: u8, strokes: Option<u8>) -> impl Iterator<Item = u32> {
+    UNIHAN.iter().filter_map(move |(codepoint, fields)| {
+        let (_, value) = fields.iter().find(|(field, _)| field == "kRSUnicode")?;
+        value
+            .split_whitespace()
+            .filter_map(RadicalStroke::parse)
+            .any(|rs| rs.radical == radical && strokes.is_none_or(|strokes| rs.strokes == strokes))
+            .then_some(*codepoint)
+    })
+}
+
+/// Get the number of the KangXi radical that a character stands for: either a KangXi radical
+/// (U+2F00 to U+2FD5), or the ideograph it is a compatibility variant of (e.g. 冂 for ⼌).
+pub fn kangxi_radical(codepoint: u32) -> Option<u8> {
+    (1..=KANGXI_RADICALS_COUNT).find(|&radical| {
+        let radical_cp = KANGXI_RADICALS_START + u32::from(radical) - 1;
+        radical_cp == codepoint
+            || decomposition(radical_cp).is_some_and(|d| d.mapping == [codepoint])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radical_stroke() {
+        let rs = RadicalStroke::parse("13.2").unwrap();
+        assert_eq!(
+            rs,
+            RadicalStroke {
+                radical: 13,
+                simplified: false,
+                strokes: 2
+            }
+        );
+        assert_eq!(rs.radical_character(), Some('⼌'));
+        assert_eq!(rs.to_string(), "13.2");
+        let rs = RadicalStroke::parse("149'.7").unwrap();
+        assert!(rs.simplified);
+        assert_eq!(rs.radical_character(), None);
+        assert_eq!(rs.to_string(), "149'.7");
+        assert_eq!(
+            RadicalStroke::parse("1.0").unwrap().radical_character(),
+            Some('⼀')
+        );
+        assert_eq!(RadicalStroke::parse("13"), None);
+    }
+
+    #[test]
+    fn test_unihan() {
+        let yen = unihan(0x5186).unwrap(); // 円
+        assert!(yen.definition.unwrap().contains("circle"));
+        assert_eq!(yen.mandarin, ["yuán"]);
+        assert_eq!(yen.cantonese, ["jyun4"]);
+        assert_eq!(yen.total_strokes, Some(4));
+        assert_eq!(yen.radical_stroke[0].to_string(), "13.2");
+        let one = unihan(0x4E00).unwrap(); // 一
+        assert_eq!(one.hangul, ["일"]);
+        assert_eq!(unihan(0x41), None);
+    }
+
+    #[test]
+    fn test_characters_with_radical() {
+        let found: Vec<u32> = characters_with_radical(13, Some(2)).collect();
+        assert!(found.contains(&0x5186)); // 円
+        assert!(!found.contains(&0x5182)); // 冂
+        assert!(!found.contains(&0x518D)); // 再
+        let all: Vec<u32> = characters_with_radical(13, None).collect();
+        assert!(all.contains(&0x5182) && all.contains(&0x518D));
+        assert!(all.is_sorted());
+    }
+
+    #[test]
+    fn test_kangxi_radical() {
+        assert_eq!(kangxi_radical(0x2F0C), Some(13)); // ⼌
+        assert_eq!(kangxi_radical(0x5182), Some(13)); // 冂
+        assert_eq!(kangxi_radical(0x4E00), Some(1)); // 一
+        assert_eq!(kangxi_radical(0x5186), None); // 円
+    }
+}
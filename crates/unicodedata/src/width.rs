@@ -0,0 +1,181 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::properties::{BinaryProperty, has_property};
+use crate::tables::{EAST_ASIAN_WIDTH, general_category_abbr, lookup};
+
+/// Value of the East Asian Width property (UAX #11).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EastAsianWidth {
+    Ambiguous,
+    Fullwidth,
+    Halfwidth,
+    Neutral,
+    Narrow,
+    Wide,
+}
+
+impl EastAsianWidth {
+    /// Parse the abbreviation used in the Unicode Character Database.
+    fn from_abbr(abbr: &str) -> Option<Self> {
+        match abbr {
+            "A" => Some(Self::Ambiguous),
+            "F" => Some(Self::Fullwidth),
+            "H" => Some(Self::Halfwidth),
+            "N" => Some(Self::Neutral),
+            "Na" => Some(Self::Narrow),
+            "W" => Some(Self::Wide),
+            _ => None,
+        }
+    }
+
+    /// Abbreviation of the value, as used in the Unicode Character Database.
+    pub fn abbr(self) -> &'static str {
+        match self {
+            Self::Ambiguous => "A",
+            Self::Fullwidth => "F",
+            Self::Halfwidth => "H",
+            Self::Neutral => "N",
+            Self::Narrow => "Na",
+            Self::Wide => "W",
+        }
+    }
+
+    /// Full name of the value.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Ambiguous => "Ambiguous",
+            Self::Fullwidth => "Fullwidth",
+            Self::Halfwidth => "Halfwidth",
+            Self::Neutral => "Neutral",
+            Self::Narrow => "Narrow",
+            Self::Wide => "Wide",
+        }
+    }
+}
+
+/// Get the East Asian Width of a codepoint.
+pub fn east_asian_width(codepoint: u32) -> EastAsianWidth {
+    lookup(&EAST_ASIAN_WIDTH, codepoint)
+        .and_then(|abbr| EastAsianWidth::from_abbr(abbr))
+        .unwrap_or(EastAsianWidth::Neutral)
+}
+
+/// How to treat characters whose East Asian Width is ambiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmbiguousWidth {
+    /// One column, as in most terminals.
+    #[default]
+    Narrow,
+    /// Two columns, as in terminals set up for East Asian legacy encodings.
+    Wide,
+}
+
+/// Get the number of columns a codepoint takes when displayed in a terminal.
+///
+/// This follows the usual `wcwidth` conventions:
+///
+/// - control characters, surrogates, nonspacing and enclosing marks, format characters and other
+///   default ignorable codepoints take no columns, except for U+00AD SOFT HYPHEN;
+/// - Hangul medial vowels and final consonants take no columns, since they join the preceding
+///   leading consonant;
+/// - wide and fullwidth characters, as well as emoji presented as such by default, take two;
+/// - ambiguous characters take one or two, as given by `ambiguous`;
+/// - anything else takes one.
+pub fn display_width(codepoint: u32, ambiguous: AmbiguousWidth) -> usize {
+    if codepoint == 0xAD {
+        return 1;
+    }
+    if matches!(
+        general_category_abbr(codepoint),
+        "Cc" | "Cs" | "Mn" | "Me" | "Cf"
+    ) || has_property(codepoint, BinaryProperty::DefaultIgnorableCodePoint)
+        || matches!(codepoint, 0x1160..=0x11FF | 0xD7B0..=0xD7FF)
+    {
+        return 0;
+    }
+    if has_property(codepoint, BinaryProperty::EmojiPresentation) {
+        return 2;
+    }
+    match east_asian_width(codepoint) {
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => 2,
+        EastAsianWidth::Ambiguous if ambiguous == AmbiguousWidth::Wide => 2,
+        _ => 1,
+    }
+}
+
+/// Get the number of columns a string takes when displayed in a terminal.
+pub fn str_width(s: &str, ambiguous: AmbiguousWidth) -> usize {
+    s.chars()
+        .map(|ch| display_width(ch as u32, ambiguous))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_east_asian_width() {
+        assert_eq!(east_asian_width(0x41), EastAsianWidth::Narrow);
+        assert_eq!(east_asian_width(0xC6), EastAsianWidth::Ambiguous);
+        assert_eq!(east_asian_width(0xC1), EastAsianWidth::Neutral);
+        assert_eq!(east_asian_width(0x5186), EastAsianWidth::Wide);
+        assert_eq!(east_asian_width(0xFF21), EastAsianWidth::Fullwidth);
+        assert_eq!(east_asian_width(0xFF71), EastAsianWidth::Halfwidth);
+        assert_eq!(east_asian_width(0x0600), EastAsianWidth::Neutral);
+    }
+
+    #[test]
+    fn test_display_width() {
+        use AmbiguousWidth::*;
+        assert_eq!(display_width(0x41, Narrow), 1);
+        assert_eq!(display_width(0x5186, Narrow), 2);
+        assert_eq!(display_width(0xFF21, Narrow), 2);
+        assert_eq!(display_width(0xFF71, Narrow), 1);
+        assert_eq!(display_width(0x1F44D, Narrow), 2);
+        assert_eq!(display_width(0x1F1E7, Narrow), 2);
+        // Ambiguous
+        assert_eq!(display_width(0xC6, Narrow), 1);
+        assert_eq!(display_width(0xC6, Wide), 2);
+        assert_eq!(display_width(0x2580, Wide), 2);
+        // Zero width
+        assert_eq!(display_width(0x00, Narrow), 0);
+        assert_eq!(display_width(0x0A, Narrow), 0);
+        assert_eq!(display_width(0x0301, Narrow), 0);
+        assert_eq!(display_width(0x20DD, Narrow), 0);
+        assert_eq!(display_width(0x200B, Narrow), 0);
+        assert_eq!(display_width(0x200D, Narrow), 0);
+        assert_eq!(display_width(0xFE0F, Narrow), 0);
+        assert_eq!(display_width(0x1161, Narrow), 0);
+        assert_eq!(display_width(0xD800, Narrow), 0);
+        // Spacing marks and the soft hyphen
+        assert_eq!(display_width(0x0903, Narrow), 1);
+        assert_eq!(display_width(0xAD, Narrow), 1);
+    }
+
+    #[test]
+    fn test_str_width() {
+        assert_eq!(str_width("", AmbiguousWidth::Narrow), 0);
+        assert_eq!(str_width("ucinfo", AmbiguousWidth::Narrow), 6);
+        assert_eq!(str_width("A\u{301}円", AmbiguousWidth::Narrow), 3);
+        assert_eq!(str_width("Æ円", AmbiguousWidth::Wide), 4);
+        assert_eq!(str_width("한국어", AmbiguousWidth::Narrow), 6);
+        assert_eq!(str_width("\u{1100}\u{1161}", AmbiguousWidth::Narrow), 2);
+    }
+}
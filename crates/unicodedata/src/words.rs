@@ -0,0 +1,153 @@
+////////       This file is part of the source code for ucinfo, a CLI tool to show         ////////
+////////       information about Unicode characters.                                       ////////
+////////                                                                                   ////////
+////////       Copyright © 2024  André Kugland                                             ////////
+////////                                                                                   ////////
+////////       This program is free software: you can redistribute it and/or modify        ////////
+////////       it under the terms of the GNU General Public License as published by        ////////
+////////       the Free Software Foundation, either version 3 of the License, or           ////////
+////////       (at your option) any later version.                                         ////////
+////////                                                                                   ////////
+////////       This program is distributed in the hope that it will be useful,             ////////
+////////       but WITHOUT ANY WARRANTY; without even the implied warranty of              ////////
+////////       MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the                ////////
+////////       GNU General Public License for more details.                                ////////
+////////                                                                                   ////////
+////////       You should have received a copy of the GNU General Public License           ////////
+////////       along with this program. If not, see https://www.gnu.org/licenses/.         ////////
+
+use crate::breaks::{WordBreak, word_break};
+use crate::properties::{BinaryProperty, has_property};
+
+/// Whether a value is ignored by rule WB4, which attaches it to the character before it.
+fn is_ignored(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::Extend | WordBreak::Format | WordBreak::Zwj)
+}
+
+/// Whether a value is a letter, for the rules using `AHLetter`.
+fn is_ahletter(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::ALetter | WordBreak::HebrewLetter)
+}
+
+/// Whether a value may appear between letters, for the rules using `MidNumLetQ`.
+fn is_mid_num_let_q(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::MidNumLet | WordBreak::SingleQuote)
+}
+
+/// Find the word boundaries of a text, following the rules of UAX #29: the result has an entry
+/// for each character, telling whether a word starts at it.
+pub(crate) fn word_boundaries(chars: &[char]) -> Vec<bool> {
+    use WordBreak as Wb;
+    let classes: Vec<Wb> = chars.iter().map(|&ch| word_break(u32::from(ch))).collect();
+
+    // The characters that rule WB4 doesn’t ignore, whose neighbours are used by the later rules.
+    let significant: Vec<usize> = (0..chars.len())
+        .filter(|&idx| {
+            idx == 0
+                || !is_ignored(classes[idx])
+                || matches!(classes[idx - 1], Wb::CR | Wb::LF | Wb::Newline)
+        })
+        .collect();
+    let class_at = |pos: Option<usize>| pos.map_or(Wb::Other, |pos| classes[significant[pos]]);
+
+    let mut boundaries = vec![false; chars.len()];
+    let mut regional_indicators = 0usize;
+    for (pos, &idx) in significant.iter().enumerate() {
+        let prev = classes[idx.saturating_sub(1)];
+        let curr = classes[idx];
+        let left = class_at(pos.checked_sub(1));
+        let before_left = class_at(pos.checked_sub(2));
+        let right = class_at(Some(pos + 1).filter(|&next| next < significant.len()));
+        boundaries[idx] = match (left, curr) {
+            _ if idx == 0 => true,                                      // WB1
+            _ if prev == Wb::CR && curr == Wb::LF => false,             // WB3
+            _ if matches!(prev, Wb::CR | Wb::LF | Wb::Newline) => true, // WB3a
+            (_, Wb::CR | Wb::LF | Wb::Newline) => true,                 // WB3b
+            _ if prev == Wb::Zwj
+                && has_property(u32::from(chars[idx]), BinaryProperty::ExtendedPictographic) =>
+            {
+                false // WB3c
+            }
+            _ if prev == Wb::WSegSpace && curr == Wb::WSegSpace => false, // WB3d
+            _ if is_ignored(curr) => false,                               // WB4
+            (l, r) if is_ahletter(l) && is_ahletter(r) => false,          // WB5
+            (l, Wb::MidLetter | Wb::MidNumLet | Wb::SingleQuote)
+                if is_ahletter(l) && is_ahletter(right) =>
+            {
+                false // WB6
+            }
+            (Wb::MidLetter | Wb::MidNumLet | Wb::SingleQuote, r)
+                if is_ahletter(before_left) && is_ahletter(r) =>
+            {
+                false // WB7
+            }
+            (Wb::HebrewLetter, Wb::SingleQuote) => false, // WB7a
+            (Wb::HebrewLetter, Wb::DoubleQuote) if right == Wb::HebrewLetter => false, // WB7b
+            (Wb::DoubleQuote, Wb::HebrewLetter) if before_left == Wb::HebrewLetter => false, // WB7c
+            (Wb::Numeric, Wb::Numeric) => false,          // WB8
+            (l, Wb::Numeric) if is_ahletter(l) => false,  // WB9
+            (Wb::Numeric, r) if is_ahletter(r) => false,  // WB10
+            (l, Wb::Numeric) if l == Wb::MidNum || is_mid_num_let_q(l) => {
+                before_left != Wb::Numeric // WB11
+            }
+            (Wb::Numeric, r) if r == Wb::MidNum || is_mid_num_let_q(r) => {
+                right != Wb::Numeric // WB12
+            }
+            (Wb::Katakana, Wb::Katakana) => false, // WB13
+            (l, Wb::ExtendNumLet)
+                if is_ahletter(l) || matches!(l, Wb::Numeric | Wb::Katakana | Wb::ExtendNumLet) =>
+            {
+                false // WB13a
+            }
+            (Wb::ExtendNumLet, r) if is_ahletter(r) || matches!(r, Wb::Numeric | Wb::Katakana) => {
+                false // WB13b
+            }
+            (Wb::RegionalIndicator, Wb::RegionalIndicator) => {
+                regional_indicators.is_multiple_of(2) // WB15, WB16
+            }
+            _ => true, // WB999
+        };
+        regional_indicators = if curr == Wb::RegionalIndicator {
+            regional_indicators + 1
+        } else {
+            0
+        };
+    }
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Split a text into words, including the spaces and punctuation between them.
+    fn words(text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut words: Vec<String> = Vec::new();
+        for (ch, boundary) in chars.iter().zip(word_boundaries(&chars)) {
+            if boundary {
+                words.push(String::new());
+            }
+            words.last_mut().unwrap().push(*ch);
+        }
+        words
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert_eq!(
+            words("The quick (“brown”) fox can’t jump 32.3 feet, right?"),
+            [
+                "The", " ", "quick", " ", "(", "“", "brown", "”", ")", " ", "fox", " ", "can’t",
+                " ", "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?"
+            ]
+        );
+        assert_eq!(
+            words("e\u{301}te\u{301} a\r\nb"),
+            ["e\u{301}te\u{301}", " ", "a", "\r\n", "b"]
+        );
+        assert_eq!(words("snake_case x86"), ["snake_case", " ", "x86"]);
+        assert_eq!(words("🇧🇷🇵🇹"), ["🇧🇷", "🇵🇹"]);
+        assert!(word_boundaries(&[]).is_empty());
+    }
+}